@@ -0,0 +1,229 @@
+use winit::event::VirtualKeyCode;
+use winit_input_helper::{TextChar, WinitInputHelper};
+use crate::images::Image;
+use crate::ui::{self, Rect};
+use crate::{BLACK, WHITE};
+
+const PIXEL:   usize = 20;
+const CANVAS:  Rect = Rect::new(32, 32, 32 * PIXEL, 32 * PIXEL);
+const PANEL_X: usize = 704;
+const NAME:    Rect = Rect::new(PANEL_X, 48, 240, 24);
+const PENCIL:  Rect = Rect::new(PANEL_X, 96, 76, 24);
+const FILL:    Rect = Rect::new(PANEL_X + 82, 96, 76, 24);
+const PICK:    Rect = Rect::new(PANEL_X + 164, 96, 76, 24);
+const SWATCH:  usize = 48;
+const PALETTE_Y: usize = 136;
+const CURRENT: Rect = Rect::new(PANEL_X, 344, 40, 40);
+const SOLID:   Rect = Rect::new(PANEL_X, 400, 240, 24);
+const HAZARD:  Rect = Rect::new(PANEL_X, 432, 240, 24);
+const CHECKPOINT: Rect = Rect::new(PANEL_X, 464, 240, 24);
+const SAVE:    Rect = Rect::new(PANEL_X, 512, 116, 32);
+const CANCEL:  Rect = Rect::new(PANEL_X + 124, 512, 116, 32);
+const NAME_LENGTH: usize = 18;
+
+const GREY:   &[u8; 4] = &[0x80, 0x80, 0x80, 0xff];
+const LIGHT:  &[u8; 4] = &[0xcc, 0xcc, 0xcc, 0xff];
+const DARK:   &[u8; 4] = &[0x30, 0x30, 0x30, 0xff];
+
+const PALETTE: [[u8; 4]; 20] = [
+    [0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x00, 0xff], [0x66, 0x66, 0x66, 0xff], [0x7f, 0x7f, 0x7f, 0xff], [0xff, 0xff, 0xff, 0xff],
+    [0x47, 0x2d, 0x3c, 0xff], [0x5e, 0x36, 0x43, 0xff], [0x4e, 0x3a, 0x24, 0xff], [0x7a, 0x44, 0x4a, 0xff], [0xbe, 0x4a, 0x2f, 0xff],
+    [0xa1, 0xef, 0x79, 0xff], [0x3f, 0xc7, 0x78, 0xff], [0x00, 0xa3, 0x83, 0xff], [0x1e, 0x6f, 0x50, 0xff], [0xfe, 0xe7, 0x61, 0xff],
+    [0xff, 0xfe, 0x00, 0xff], [0xf7, 0x76, 0x22, 0xff], [0xe4, 0x3b, 0x44, 0xff], [0x12, 0x4e, 0x89, 0xff], [0x2c, 0xe8, 0xf5, 0xff],
+];
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BlockTool {
+    Pencil,
+    Fill,
+    Eyedropper,
+}
+
+/// What the world should do after the block editor handled an input event
+pub enum BlockAction {
+    None,
+    Save,
+    Close,
+}
+
+/// A 32x32 pixel canvas for drawing a new block type
+pub struct BlockEditor {
+    pub canvas: Image,
+    pub name: String,
+    pub solid: bool,
+    pub hazard: bool,
+    pub checkpoint: bool,
+    pub message: Option<String>,
+    color: [u8; 4],
+    tool: BlockTool,
+    naming: bool,
+}
+
+impl BlockEditor {
+    /// Starts from a copy of `base`, usually the art of the selected tile
+    pub fn new(base: &Image) -> Self {
+        BlockEditor {
+            canvas: *base,
+            name: String::new(),
+            solid: true,
+            hazard: false,
+            checkpoint: false,
+            message: None,
+            color: PALETTE[1],
+            tool: BlockTool::Pencil,
+            naming: true,
+        }
+    }
+
+    /// While the name is being typed keys go into it, otherwise P, F and I pick the pencil,
+    /// fill and eyedropper, Return saves and Escape closes the editor. `mouse` is the cursor in frame pixels.
+    pub fn update(&mut self, input: WinitInputHelper, mouse: Option<(f32, f32)>) -> BlockAction {
+        if self.naming {
+            for c in input.text() {
+                match c {
+                    TextChar::Char(c) if (c.is_ascii_alphanumeric() || c == ' ' || c == '_') && self.name.len() < NAME_LENGTH => self.name.push(c),
+                    TextChar::Back => { self.name.pop(); }
+                    _ => {}
+                }
+            }
+            if input.key_pressed(VirtualKeyCode::Return) || input.key_pressed(VirtualKeyCode::Tab) || input.key_pressed(VirtualKeyCode::Escape) {
+                self.naming = false;
+            }
+        } else if input.key_pressed(VirtualKeyCode::Escape) {
+            return BlockAction::Close;
+        } else if input.key_pressed(VirtualKeyCode::Return) {
+            return BlockAction::Save;
+        } else if input.key_pressed(VirtualKeyCode::P) {
+            self.tool = BlockTool::Pencil;
+        } else if input.key_pressed(VirtualKeyCode::F) {
+            self.tool = BlockTool::Fill;
+        } else if input.key_pressed(VirtualKeyCode::I) {
+            self.tool = BlockTool::Eyedropper;
+        } else if input.key_pressed(VirtualKeyCode::Tab) {
+            self.naming = true;
+        }
+
+        let mouse = match mouse {
+            Some(mouse) => mouse,
+            None => return BlockAction::None,
+        };
+        if input.mouse_held(0) && CANVAS.contains(mouse) {
+            let x = (mouse.0 as usize - CANVAS.x) / PIXEL;
+            let y = (mouse.1 as usize - CANVAS.y) / PIXEL;
+            match self.tool {
+                BlockTool::Pencil => self.canvas[y][x] = self.color,
+                BlockTool::Fill if input.mouse_pressed(0) => self.fill(x, y),
+                BlockTool::Eyedropper => {
+                    self.color = self.canvas[y][x];
+                    self.tool = BlockTool::Pencil;
+                }
+                _ => {}
+            }
+        }
+        if !input.mouse_pressed(0) {
+            return BlockAction::None;
+        }
+
+        self.naming = NAME.contains(mouse);
+        if let Some(i) = (0..PALETTE.len()).find(|&i| swatch(i).contains(mouse)) {
+            self.color = PALETTE[i];
+        } else if PENCIL.contains(mouse) {
+            self.tool = BlockTool::Pencil;
+        } else if FILL.contains(mouse) {
+            self.tool = BlockTool::Fill;
+        } else if PICK.contains(mouse) {
+            self.tool = BlockTool::Eyedropper;
+        } else if SOLID.contains(mouse) {
+            self.solid = !self.solid;
+        } else if HAZARD.contains(mouse) {
+            self.hazard = !self.hazard;
+        } else if CHECKPOINT.contains(mouse) {
+            self.checkpoint = !self.checkpoint;
+        } else if SAVE.contains(mouse) {
+            return BlockAction::Save;
+        } else if CANCEL.contains(mouse) {
+            return BlockAction::Close;
+        }
+        BlockAction::None
+    }
+
+    /// Replaces the 4-connected area of the clicked color with the current color
+    fn fill(&mut self, x: usize, y: usize) {
+        let target = self.canvas[y][x];
+        if target == self.color {
+            return;
+        }
+        let mut stack = vec![(x, y)];
+        while let Some((x, y)) = stack.pop() {
+            if self.canvas[y][x] != target {
+                continue;
+            }
+            self.canvas[y][x] = self.color;
+            if x > 0 { stack.push((x - 1, y)); }
+            if y > 0 { stack.push((x, y - 1)); }
+            if x < 31 { stack.push((x + 1, y)); }
+            if y < 31 { stack.push((x, y + 1)); }
+        }
+    }
+
+    pub fn draw(&self, frame: &mut [u8], width: usize) {
+        ui::fill_rect(frame, width, Rect::new(0, 0, width, frame.len() / 4 / width), DARK);
+
+        for (y, row) in self.canvas.iter().enumerate() {
+            for (x, rgba) in row.iter().enumerate() {
+                let cell = Rect::new(CANVAS.x + x * PIXEL, CANVAS.y + y * PIXEL, PIXEL, PIXEL);
+                ui::fill_rect(frame, width, cell, &blend_checker(*rgba, x + y));
+            }
+        }
+        ui::stroke_rect(frame, width, CANVAS, GREY);
+
+        ui::draw_text(frame, width, PANEL_X, 32, "Name", LIGHT, 2);
+        ui::fill_rect(frame, width, NAME, if self.naming { WHITE } else { LIGHT });
+        let cursor = if self.naming { "_" } else { "" };
+        ui::draw_text(frame, width, NAME.x + 4, NAME.y + 4, &format!("{}{}", self.name, cursor), BLACK, 2);
+
+        button(frame, width, PENCIL, "Pencil", self.tool == BlockTool::Pencil);
+        button(frame, width, FILL, "Fill", self.tool == BlockTool::Fill);
+        button(frame, width, PICK, "Pick", self.tool == BlockTool::Eyedropper);
+
+        for (i, rgba) in PALETTE.iter().enumerate() {
+            ui::fill_rect(frame, width, swatch(i), &blend_checker(*rgba, i));
+            if *rgba == self.color {
+                ui::stroke_rect(frame, width, swatch(i), WHITE);
+            }
+        }
+        ui::fill_rect(frame, width, CURRENT, &blend_checker(self.color, 0));
+        ui::stroke_rect(frame, width, CURRENT, GREY);
+        let hex = format!("#{:02x}{:02x}{:02x}{:02x}", self.color[0], self.color[1], self.color[2], self.color[3]);
+        ui::draw_text(frame, width, CURRENT.x + CURRENT.w + 8, CURRENT.y + 12, &hex, LIGHT, 2);
+
+        button(frame, width, SOLID, "Solid", self.solid);
+        button(frame, width, HAZARD, "Hazard", self.hazard);
+        button(frame, width, CHECKPOINT, "Checkpoint", self.checkpoint);
+        button(frame, width, SAVE, "Save", false);
+        button(frame, width, CANCEL, "Cancel", false);
+
+        if let Some(message) = &self.message {
+            ui::draw_text(frame, width, PANEL_X, SAVE.y + SAVE.h + 16, message, &[0xff, 0x60, 0x60, 0xff], 1);
+        }
+    }
+}
+
+fn swatch(i: usize) -> Rect {
+    Rect::new(PANEL_X + i % 5 * SWATCH, PALETTE_Y + i / 5 * SWATCH, SWATCH - 4, SWATCH - 4)
+}
+
+fn button(frame: &mut [u8], width: usize, rect: Rect, label: &str, active: bool) {
+    ui::fill_rect(frame, width, rect, if active { LIGHT } else { GREY });
+    let x = rect.x + rect.w.saturating_sub(ui::text_width(label, 2)) / 2;
+    let y = rect.y + rect.h.saturating_sub(ui::GLYPH_HEIGHT * 2) / 2 + 1;
+    ui::draw_text(frame, width, x, y, label, BLACK, 2);
+}
+
+/// Shows translucent pixels over a checkerboard so transparency stays visible
+fn blend_checker(rgba: [u8; 4], parity: usize) -> [u8; 4] {
+    let back = if parity.is_multiple_of(2) { 0x99 } else { 0x66 };
+    let a = rgba[3] as u16;
+    let mix = |c: u8| ((c as u16 * a + back as u16 * (255 - a)) / 255) as u8;
+    [mix(rgba[0]), mix(rgba[1]), mix(rgba[2]), 0xff]
+}
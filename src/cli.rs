@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+use clap::Parser;
+use crate::format::MapFormat;
+use crate::render::ImageLayers;
+
+/// A level editor for Trump Run
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Args {
+    /// Foreground map file, the background map and spawn json are kept next to it
+    #[arg(default_value = "map.txt")]
+    pub map: PathBuf,
+
+    /// Directory the level files are written to
+    #[arg(long, value_name = "DIR")]
+    pub out_dir: Option<PathBuf>,
+
+    /// Width of a new level in tiles
+    #[arg(long, default_value_t = 40)]
+    pub width: usize,
+
+    /// Height of a new level in tiles
+    #[arg(long, default_value_t = 30)]
+    pub height: usize,
+
+    /// Load the level from this map, project or Tiled (.tmx) file instead of the output location
+    #[arg(long, value_name = "MAP")]
+    pub open: Option<PathBuf>,
+
+    /// Tile definition file, defaults to the game's own tiles
+    #[arg(long, value_name = "FILE")]
+    pub tiles: Option<PathBuf>,
+
+    /// Start from a PNG where every pixel is a foreground tile, picked by its colour
+    #[arg(long, value_name = "PNG")]
+    pub import: Option<PathBuf>,
+
+    /// Background PNG for --import, the same size as the foreground one
+    #[arg(long, value_name = "PNG", requires = "import")]
+    pub import_bg: Option<PathBuf>,
+
+    /// Colour to tile mapping for --import, defaults to `assets/palette.json`
+    #[arg(long, value_name = "FILE", requires = "import")]
+    pub palette: Option<PathBuf>,
+
+    /// Number of old versions kept of each level file when saving, 0 keeps none
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub backups: usize,
+
+    /// Format the map files are saved in, defaults to the one the level was loaded from
+    /// or the single digit one the game reads for new levels
+    #[arg(long, value_enum)]
+    pub format: Option<MapFormat>,
+
+    /// Write the level to this Tiled map, with its tileset next to it, and exit instead of opening the editor
+    #[arg(long, value_name = "TMX")]
+    pub export_tmx: Option<PathBuf>,
+
+    /// Write the level to this LDtk project, with its tileset next to it, and exit instead of opening the editor
+    #[arg(long, value_name = "LDTK")]
+    pub export_ldtk: Option<PathBuf>,
+
+    /// Render the level to this PNG and exit instead of opening the editor
+    #[arg(long, value_name = "PNG")]
+    pub export_image: Option<PathBuf>,
+
+    /// Layers shown in exported images, also used by Ctrl+E
+    #[arg(long, value_enum, default_value_t = ImageLayers::Both)]
+    pub image_layers: ImageLayers,
+
+    /// Size of exported images relative to the editor, 0.5 halves them and 2 doubles them
+    #[arg(long, value_name = "FACTOR", default_value_t = 1.0, value_parser = parse_scale)]
+    pub image_scale: f32,
+}
+
+impl Args {
+    /// Where the foreground map is saved to
+    pub fn map_path(&self) -> PathBuf {
+        match &self.out_dir {
+            Some(dir) => dir.join(self.map.file_name().unwrap_or(self.map.as_os_str())),
+            None => self.map.clone(),
+        }
+    }
+}
+
+fn parse_scale(text: &str) -> Result<f32, String> {
+    match text.parse::<f32>() {
+        Ok(scale) if scale > 0.0 && scale <= 16.0 => Ok(scale),
+        Ok(_) => Err("the scale has to be above 0 and at most 16".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
@@ -0,0 +1,40 @@
+use clap::ValueEnum;
+
+/// Starts the first line of a versioned map, followed by the version number
+pub const HEADER:  &str = "#trumptor-map";
+pub const VERSION: &str = "2";
+
+/// How tile ids are laid out in the map text files
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+pub enum MapFormat {
+    /// A digit per tile without separators, what the game reads. Only fits ids 0 to 9
+    Legacy,
+    /// A `#trumptor-map 2` header followed by rows of comma separated ids
+    V2,
+}
+
+impl MapFormat {
+    /// The largest tile id the format can store
+    pub fn max_id(self) -> u16 {
+        match self {
+            MapFormat::Legacy => 9,
+            MapFormat::V2 => u16::MAX,
+        }
+    }
+
+    /// Splits a row into the text of each tile id
+    pub fn split_row(self, row: &str) -> Vec<&str> {
+        match self {
+            MapFormat::Legacy => row.char_indices().map(|(i, c)| &row[i..i + c.len_utf8()]).collect(),
+            MapFormat::V2 => row.split(',').map(str::trim).collect(),
+        }
+    }
+
+    /// What goes between the ids of a row
+    pub fn separator(self) -> &'static str {
+        match self {
+            MapFormat::Legacy => "",
+            MapFormat::V2 => ",",
+        }
+    }
+}
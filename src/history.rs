@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+use crate::layer::Layer;
+use crate::meta::LevelMeta;
+
+/// The part of a `World` that undo and redo restore
+#[derive(Clone, PartialEq)]
+pub struct Snapshot {
+    pub chunks: [Layer; 2],
+    pub spawn_chunk: (usize, usize),
+    pub chunk_mode: usize,
+    pub meta: LevelMeta,
+}
+
+impl Snapshot {
+    /// Whether both hold the same level, ignoring which layer is being edited
+    pub fn same_level(&self, other: &Snapshot) -> bool {
+        self.chunks == other.chunks && self.spawn_chunk == other.spawn_chunk && self.meta == other.meta
+    }
+}
+
+/// A bounded undo/redo stack of snapshots taken before each edit
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    stroke: Option<Snapshot>,
+    limit: usize,
+}
+
+impl History {
+    pub fn new(limit: usize) -> Self {
+        History {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            stroke: None,
+            limit,
+        }
+    }
+
+    /// Records the state from before an edit, unless the edit did not change anything
+    pub fn record(&mut self, before: Snapshot, after: &Snapshot) {
+        if before == *after {
+            return;
+        }
+        if self.undo.len() == self.limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before);
+        self.redo.clear();
+    }
+
+    /// Starts a stroke, every edit until `end_stroke` becomes a single entry
+    pub fn begin_stroke(&mut self, before: Snapshot) {
+        if self.stroke.is_none() {
+            self.stroke = Some(before);
+        }
+    }
+
+    pub fn end_stroke(&mut self, after: &Snapshot) {
+        if let Some(before) = self.stroke.take() {
+            self.record(before, after);
+        }
+    }
+
+    /// Returns the state to go back to, `current` becomes redoable
+    pub fn undo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        self.end_stroke(&current);
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// Returns the state to go forward to, `current` becomes undoable again
+    pub fn redo(&mut self, current: Snapshot) -> Option<Snapshot> {
+        self.end_stroke(&current);
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        Some(next)
+    }
+}
@@ -1,10 +1,180 @@
-pub const DIRT: [[[u8; 4]; 32]; 32] = [[[71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]]];
-pub const GRASS: [[[u8; 4]; 32]; 32] = [[[161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [161, 239, 121, 255], [161, 239, 121, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255]], [[161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [161, 239, 121, 255], [161, 239, 121, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255]], [[63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255]], [[63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [161, 239, 121, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255]], [[0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [161, 239, 121, 255], [161, 239, 121, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [0, 99, 92, 255], [0, 99, 92, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [0, 99, 92, 255], [0, 99, 92, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [0, 99, 92, 255], [0, 99, 92, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[122, 68, 74, 255], [122, 68, 74, 255], [160, 91, 83, 255], [160, 91, 83, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [122, 68, 74, 255], [122, 68, 74, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]], [[94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [71, 45, 60, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [94, 54, 67, 255], [71, 45, 60, 255], [71, 45, 60, 255]]];
-pub const CHECKPOINT: [[[u8; 4]; 32]; 32] = [[[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [20, 127, 27, 255], [20, 127, 27, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [20, 127, 27, 255], [20, 127, 27, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [49, 127, 53, 255], [49, 127, 53, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [20, 127, 27, 255], [20, 127, 27, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [112, 112, 112, 255], [112, 112, 112, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [112, 112, 112, 255], [112, 112, 112, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [144, 144, 144, 255], [144, 144, 144, 255], [96, 96, 96, 255], [96, 96, 96, 255], [112, 112, 112, 255], [112, 112, 112, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [144, 144, 144, 255], [144, 144, 144, 255], [96, 96, 96, 255], [96, 96, 96, 255], [112, 112, 112, 255], [112, 112, 112, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [128, 128, 128, 255], [128, 128, 128, 255], [128, 128, 128, 255], [128, 128, 128, 255], [96, 96, 96, 255], [96, 96, 96, 255], [96, 96, 96, 255], [96, 96, 96, 255], [112, 112, 112, 255], [112, 112, 112, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [128, 128, 128, 255], [128, 128, 128, 255], [128, 128, 128, 255], [128, 128, 128, 255], [96, 96, 96, 255], [96, 96, 96, 255], [96, 96, 96, 255], [96, 96, 96, 255], [112, 112, 112, 255], [112, 112, 112, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]]];
-pub const SPAWN: [[[u8; 4]; 32]; 32] = [[[0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]], [[255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 255], [0, 0, 0, 255], [255, 254, 0, 255]]];
-pub const SPIKES: [[[u8; 4]; 32]; 32] = [[[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 255], [128, 128, 128, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [96, 96, 96, 255], [96, 96, 96, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [96, 96, 96, 255], [96, 96, 96, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [96, 96, 96, 255], [96, 96, 96, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [144, 144, 144, 255], [144, 144, 144, 255], [96, 96, 96, 255], [96, 96, 96, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [255, 255, 255, 1], [255, 255, 255, 1], [165, 165, 165, 255], [165, 165, 165, 255], [144, 144, 144, 255], [144, 144, 144, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 255, 255, 1], [255, 255, 255, 1], [165, 165, 165, 255], [165, 165, 165, 255], [144, 144, 144, 255], [144, 144, 144, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [255, 255, 255, 1], [255, 255, 255, 1], [165, 165, 165, 255], [165, 165, 165, 255], [144, 144, 144, 255], [144, 144, 144, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 255, 255, 1], [255, 255, 255, 1], [165, 165, 165, 255], [165, 165, 165, 255], [144, 144, 144, 255], [144, 144, 144, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [135, 135, 135, 255], [135, 135, 135, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [135, 135, 135, 255], [135, 135, 135, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [135, 135, 135, 255], [135, 135, 135, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [165, 165, 165, 255], [135, 135, 135, 255], [135, 135, 135, 255], [107, 107, 107, 255], [107, 107, 107, 255], [96, 96, 96, 254], [96, 96, 96, 254], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[128, 128, 128, 2], [128, 128, 128, 2], [164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 253], [96, 96, 96, 253], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 2], [128, 128, 128, 2], [164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 253], [96, 96, 96, 253], [0, 0, 0, 0], [0, 0, 0, 0]], [[128, 128, 128, 2], [128, 128, 128, 2], [164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 253], [96, 96, 96, 253], [0, 0, 0, 0], [0, 0, 0, 0], [128, 128, 128, 2], [128, 128, 128, 2], [164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 253], [96, 96, 96, 253], [0, 0, 0, 0], [0, 0, 0, 0]], [[164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [128, 128, 128, 255], [128, 128, 128, 255], [127, 127, 127, 255], [127, 127, 127, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 255], [96, 96, 96, 255], [164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [128, 128, 128, 255], [128, 128, 128, 255], [127, 127, 127, 255], [127, 127, 127, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 255], [96, 96, 96, 255]], [[164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [128, 128, 128, 255], [128, 128, 128, 255], [127, 127, 127, 255], [127, 127, 127, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 255], [96, 96, 96, 255], [164, 164, 164, 255], [164, 164, 164, 255], [143, 143, 143, 255], [143, 143, 143, 255], [134, 134, 134, 255], [134, 134, 134, 255], [128, 128, 128, 255], [128, 128, 128, 255], [127, 127, 127, 255], [127, 127, 127, 255], [107, 107, 107, 255], [107, 107, 107, 255], [106, 106, 106, 255], [106, 106, 106, 255], [96, 96, 96, 255], [96, 96, 96, 255]]];
-pub const TALL_GRASS: [[[u8; 4]; 32]; 32] = [[[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 128, 0, 4], [0, 128, 0, 4], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 128, 0, 4], [0, 128, 0, 4], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 0, 0, 0], [0, 0, 0, 0], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 0, 0, 0], [0, 0, 0, 0], [63, 199, 120, 255], [63, 199, 120, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 0, 0, 0], [0, 0, 0, 0], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 0, 0, 0], [0, 0, 0, 0], [63, 199, 120, 255], [63, 199, 120, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255]], [[0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255]], [[63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255]], [[63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [63, 199, 120, 255], [0, 163, 131, 255], [0, 163, 131, 255], [0, 99, 92, 255], [0, 99, 92, 255]]];
-pub const STONE: [[[u8; 4]; 32]; 32] = [[[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]]];
-pub const PLANKS: [[[u8; 4]; 32]; 32] = [[[78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255]], [[78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [78, 58, 36, 255], [78, 58, 36, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [78, 58, 36, 255], [78, 58, 36, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255], [78, 58, 36, 255], [78, 58, 36, 255], [118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255], [78, 58, 36, 255], [78, 58, 36, 255], [118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255], [78, 58, 36, 255], [78, 58, 36, 255], [118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255], [78, 58, 36, 255], [78, 58, 36, 255], [118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [135, 103, 68, 255], [135, 103, 68, 255], [78, 58, 36, 255], [78, 58, 36, 255], [135, 103, 68, 255], [135, 103, 68, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [135, 103, 68, 255], [135, 103, 68, 255], [78, 58, 36, 255], [78, 58, 36, 255], [135, 103, 68, 255], [135, 103, 68, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [150, 114, 76, 255], [150, 114, 76, 255], [78, 58, 36, 255], [78, 58, 36, 255], [150, 114, 76, 255], [150, 114, 76, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [150, 114, 76, 255], [150, 114, 76, 255], [78, 58, 36, 255], [78, 58, 36, 255], [150, 114, 76, 255], [150, 114, 76, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [144, 110, 75, 255], [144, 110, 75, 255], [78, 58, 36, 255], [78, 58, 36, 255], [144, 110, 75, 255], [144, 110, 75, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [144, 110, 75, 255], [144, 110, 75, 255], [78, 58, 36, 255], [78, 58, 36, 255], [144, 110, 75, 255], [144, 110, 75, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [148, 114, 77, 255], [148, 114, 77, 255], [78, 58, 36, 255], [78, 58, 36, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255]], [[144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [148, 114, 77, 255], [148, 114, 77, 255], [78, 58, 36, 255], [78, 58, 36, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255]], [[78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255]], [[78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255], [78, 58, 36, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[118, 90, 60, 255], [118, 90, 60, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [118, 90, 60, 255], [118, 90, 60, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255], [131, 100, 67, 255]], [[144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255]], [[144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255], [144, 110, 75, 255]]];
-pub const CRACKED_STONE: [[[u8; 4]; 32]; 32] = [[[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [178, 178, 178, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[153, 153, 153, 255], [153, 153, 153, 255], [178, 178, 178, 255], [178, 178, 178, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [102, 102, 102, 255], [102, 102, 102, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [153, 153, 153, 255], [153, 153, 153, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255]], [[86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255]], [[86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [127, 127, 127, 255], [127, 127, 127, 255], [153, 153, 153, 255], [153, 153, 153, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255]], [[127, 127, 127, 255], [127, 127, 127, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [102, 102, 102, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [86, 86, 86, 255], [102, 102, 102, 255], [102, 102, 102, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [127, 127, 127, 255], [86, 86, 86, 255], [86, 86, 86, 255]]];
-pub const NOT_SHOWN: [[[u8; 4]; 32]; 32] = [[[0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255]], [[0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0]], [[0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255], [0, 0, 0, 0]], [[255, 254, 0, 255], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [0, 0, 0, 0], [255, 254, 0, 255]]];
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::Path;
+use std::sync::OnceLock;
+use png::{BitDepth, ColorType, Decoder, Encoder, Transformations};
+
+/// A 32x32 RGBA tile
+pub type Image = [[[u8; 4]; 32]; 32];
+
+/// How air is drawn
+pub const BLANK: Image = [[[0xff; 4]; 32]; 32];
+
+const DIRT:          &[u8] = include_bytes!("../assets/tiles/dirt.png");
+const GRASS:         &[u8] = include_bytes!("../assets/tiles/grass.png");
+const CHECKPOINT:    &[u8] = include_bytes!("../assets/tiles/checkpoint.png");
+const SPIKES:        &[u8] = include_bytes!("../assets/tiles/spikes.png");
+const TALL_GRASS:    &[u8] = include_bytes!("../assets/tiles/tall_grass.png");
+const STONE:         &[u8] = include_bytes!("../assets/tiles/stone.png");
+const PLANKS:        &[u8] = include_bytes!("../assets/tiles/planks.png");
+const CRACKED_STONE: &[u8] = include_bytes!("../assets/tiles/cracked_stone.png");
+const SPAWN:         &[u8] = include_bytes!("../assets/spawn.png");
+const NOT_SHOWN:     &[u8] = include_bytes!("../assets/not_shown.png");
+
+#[derive(Debug)]
+pub enum ImageError {
+    Io(io::Error),
+    Decode(png::DecodingError),
+    Encode(png::EncodingError),
+    Size { width: usize, height: usize },
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImageError::Io(e) => write!(f, "{}", e),
+            ImageError::Decode(e) => write!(f, "{}", e),
+            ImageError::Encode(e) => write!(f, "{}", e),
+            ImageError::Size { width, height } =>
+                write!(f, "image is {}x{} pixels, tiles need to be a multiple of 32x32", width, height),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// An RGBA picture of any size, as read from or written to a PNG file
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl Bitmap {
+    pub fn new(width: usize, height: usize) -> Self {
+        Bitmap { width, height, pixels: vec![[0; 4]; width * height] }
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, ImageError> {
+        let mut decoder = Decoder::new(bytes);
+        decoder.set_transformations(Transformations::normalize_to_color8() | Transformations::ALPHA);
+        let mut reader = decoder.read_info().map_err(ImageError::Decode)?;
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).map_err(ImageError::Decode)?;
+        let buf = &buf[..info.buffer_size()];
+
+        let pixels = match info.color_type {
+            ColorType::Rgba => buf.chunks_exact(4).map(|p| [p[0], p[1], p[2], p[3]]).collect(),
+            ColorType::Rgb => buf.chunks_exact(3).map(|p| [p[0], p[1], p[2], 0xff]).collect(),
+            ColorType::GrayscaleAlpha => buf.chunks_exact(2).map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+            _ => buf.iter().map(|&p| [p, p, p, 0xff]).collect(),
+        };
+        Ok(Bitmap {
+            width: info.width as usize,
+            height: info.height as usize,
+            pixels,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ImageError> {
+        Self::decode(&fs::read(path).map_err(ImageError::Io)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ImageError> {
+        let file = File::create(path).map_err(ImageError::Io)?;
+        let mut encoder = Encoder::new(BufWriter::new(file), self.width as u32, self.height as u32);
+        encoder.set_color(ColorType::Rgba);
+        encoder.set_depth(BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(ImageError::Encode)?;
+        writer.write_image_data(&self.pixels.concat()).map_err(ImageError::Encode)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> [u8; 4] {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, rgba: [u8; 4]) {
+        self.pixels[y * self.width + x] = rgba;
+    }
+
+    /// Cuts out the `index`th 32x32 cell of a tileset atlas, counting row by row
+    pub fn tile(&self, index: usize) -> Result<Option<Image>, ImageError> {
+        if !self.width.is_multiple_of(32) || !self.height.is_multiple_of(32) || self.width == 0 {
+            return Err(ImageError::Size { width: self.width, height: self.height });
+        }
+        let columns = self.width / 32;
+        let (cell_x, cell_y) = (index % columns * 32, index / columns * 32);
+        if cell_y >= self.height {
+            return Ok(None);
+        }
+        let mut image = [[[0; 4]; 32]; 32];
+        for (y, row) in image.iter_mut().enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                *pixel = self.get(cell_x + x, cell_y + y);
+            }
+        }
+        Ok(Some(image))
+    }
+
+    /// The picture as a single tile, it has to be exactly 32x32
+    pub fn to_image(&self) -> Result<Image, ImageError> {
+        if self.width != 32 || self.height != 32 {
+            return Err(ImageError::Size { width: self.width, height: self.height });
+        }
+        Ok(self.tile(0)?.unwrap_or(BLANK))
+    }
+}
+
+impl From<&Image> for Bitmap {
+    fn from(image: &Image) -> Self {
+        Bitmap { width: 32, height: 32, pixels: image.concat() }
+    }
+}
+
+fn embedded(bytes: &[u8]) -> Image {
+    Bitmap::decode(bytes)
+        .and_then(|bitmap| bitmap.to_image())
+        .expect("embedded images are valid 32x32 PNGs")
+}
+
+/// The art shipped with the editor for the game's own tiles
+pub fn builtin(name: &str) -> Option<Image> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "air"          => BLANK,
+        "dirt"         => embedded(DIRT),
+        "grass"        => embedded(GRASS),
+        "checkpoint"   => embedded(CHECKPOINT),
+        "spikes"       => embedded(SPIKES),
+        "tallgrass"    => embedded(TALL_GRASS),
+        "stone"        => embedded(STONE),
+        "planks"       => embedded(PLANKS),
+        "crackedstone" => embedded(CRACKED_STONE),
+        _ => return None,
+    })
+}
+
+/// The marker drawn over the spawn tile
+pub fn spawn() -> &'static Image {
+    static IMAGE: OnceLock<Image> = OnceLock::new();
+    IMAGE.get_or_init(|| embedded(SPAWN))
+}
+
+/// The marker drawn over background tiles hidden by the foreground
+pub fn not_shown() -> &'static Image {
+    static IMAGE: OnceLock<Image> = OnceLock::new();
+    IMAGE.get_or_init(|| embedded(NOT_SHOWN))
+}
+
+/// A magenta and black checkerboard for tiles without any art
+pub fn placeholder() -> Image {
+    let mut image = [[[0, 0, 0, 0xff]; 32]; 32];
+    for (y, row) in image.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            if (x / 8 + y / 8) % 2 == 0 {
+                *pixel = [0xff, 0x00, 0xff, 0xff];
+            }
+        }
+    }
+    image
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use crate::history::Snapshot;
+use crate::images::Bitmap;
+use crate::layer::Layer;
+use crate::meta::LevelMeta;
+use crate::tiles::TileSet;
+use crate::{ChunkType, LoadError};
+
+/// The colours used when no palette file is given
+const BUILTIN_PALETTE: &str = include_str!("../assets/palette.json");
+
+/// A tile as named in a palette, by id or by name
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TileRef {
+    Id(u16),
+    Name(String),
+}
+
+impl fmt::Display for TileRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TileRef::Id(id) => write!(f, "{}", id),
+            TileRef::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct PaletteFile {
+    /// Colour of the pixel marking the spawn
+    #[serde(default)]
+    spawn: Option<String>,
+    /// `#rrggbb` colours and the tile each stands for
+    colors: HashMap<String, TileRef>,
+}
+
+/// Which tile each colour of a colour-key image stands for. Fully transparent pixels are always air.
+pub struct Palette {
+    colors: HashMap<[u8; 3], ChunkType>,
+    spawn: Option<[u8; 3]>,
+}
+
+impl Palette {
+    pub fn builtin(tiles: &TileSet) -> Result<Self, LoadError> {
+        Self::parse(Path::new("assets/palette.json"), BUILTIN_PALETTE, tiles)
+    }
+
+    pub fn load(path: &Path, tiles: &TileSet) -> Result<Self, LoadError> {
+        let text = fs::read_to_string(path).map_err(|e| LoadError::Io(path.to_path_buf(), e))?;
+        Self::parse(path, &text, tiles)
+    }
+
+    fn parse(path: &Path, text: &str, tiles: &TileSet) -> Result<Self, LoadError> {
+        let file: PaletteFile = serde_json::from_str(text).map_err(|e| LoadError::Json(path.to_path_buf(), e))?;
+        let color = |text: &str| parse_color(text).ok_or_else(|| LoadError::BadColor { path: path.to_path_buf(), color: text.to_string() });
+        let mut colors = HashMap::new();
+        for (key, tile) in &file.colors {
+            let chunk = match tile {
+                TileRef::Id(id) => Some(ChunkType(*id)).filter(|chunk| tiles.contains(*chunk)),
+                TileRef::Name(name) => tiles.find(name),
+            };
+            let chunk = chunk.ok_or_else(|| LoadError::UnknownTileName { path: path.to_path_buf(), name: tile.to_string() })?;
+            colors.insert(color(key)?, chunk);
+        }
+        let spawn = file.spawn.as_deref().map(color).transpose()?;
+        Ok(Palette { colors, spawn })
+    }
+
+    /// Builds a level from a foreground image and, if given, a background image of the same size.
+    /// The spawn is the first pixel with the spawn colour, the tile under it is left as air.
+    pub fn import(&self, fg: &Path, bg: Option<&Path>) -> Result<Snapshot, LoadError> {
+        let load = |path: &Path| Bitmap::load(path).map_err(|e| LoadError::Image(path.to_path_buf(), e));
+        let fg_image = load(fg)?;
+        if fg_image.width == 0 || fg_image.height == 0 {
+            return Err(LoadError::InvalidSize { path: fg.to_path_buf(), width: fg_image.width, height: fg_image.height });
+        }
+        let mut spawn = None;
+        let foreground = self.layer(fg, &fg_image, &mut spawn)?;
+        let background = match bg {
+            Some(bg) => {
+                let bg_image = load(bg)?;
+                if bg_image.width != fg_image.width {
+                    return Err(LoadError::WrongWidth { path: bg.to_path_buf(), line: 1, expected: fg_image.width, found: bg_image.width });
+                }
+                if bg_image.height != fg_image.height {
+                    return Err(LoadError::WrongHeight { path: bg.to_path_buf(), expected: fg_image.height, found: bg_image.height });
+                }
+                self.layer(bg, &bg_image, &mut spawn)?
+            }
+            None => Layer::new(fg_image.width, fg_image.height),
+        };
+        Ok(Snapshot {
+            chunks: [foreground, background],
+            spawn_chunk: spawn.unwrap_or((0, 0)),
+            chunk_mode: 0,
+            meta: LevelMeta::default(),
+        })
+    }
+
+    fn layer(&self, path: &Path, image: &Bitmap, spawn: &mut Option<(usize, usize)>) -> Result<Layer, LoadError> {
+        let mut layer = Layer::new(image.width, image.height);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                let [r, g, b, a] = image.get(x, y);
+                let rgb = [r, g, b];
+                if a == 0 {
+                    continue;
+                }
+                if self.spawn == Some(rgb) {
+                    spawn.get_or_insert((y, x));
+                    continue;
+                }
+                layer[y][x] = *self.colors.get(&rgb).ok_or_else(|| LoadError::UnknownColor {
+                    path: path.to_path_buf(),
+                    line: y + 1,
+                    column: x + 1,
+                    color: format!("#{:02x}{:02x}{:02x}", r, g, b),
+                })?;
+            }
+        }
+        Ok(layer)
+    }
+}
+
+/// Reads `#rrggbb`, the leading `#` is optional
+fn parse_color(text: &str) -> Option<[u8; 3]> {
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
@@ -0,0 +1,91 @@
+use std::ops::{Index, IndexMut};
+use crate::ChunkType;
+
+/// A heap allocated grid of tiles, indexed as `layer[y][x]`
+#[derive(Clone, PartialEq)]
+pub struct Layer {
+    width: usize,
+    height: usize,
+    tiles: Vec<ChunkType>,
+}
+
+impl Layer {
+    pub fn new(width: usize, height: usize) -> Self {
+        Layer {
+            width,
+            height,
+            tiles: vec![ChunkType::AIR; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<ChunkType> {
+        if x < self.width && y < self.height {
+            Some(self.tiles[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Adds (or with negative values removes) rows and columns on each edge,
+    /// keeping the existing tiles in place relative to each other
+    pub fn resized(&self, left: isize, top: isize, right: isize, bottom: isize) -> Self {
+        let width  = (self.width  as isize + left + right).max(1) as usize;
+        let height = (self.height as isize + top + bottom).max(1) as usize;
+        let mut layer = Layer::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let (old_x, old_y) = (x as isize - left, y as isize - top);
+                if old_x < 0 || old_y < 0 {
+                    continue;
+                }
+                if let Some(chunk) = self.get(old_x as usize, old_y as usize) {
+                    layer[y][x] = chunk;
+                }
+            }
+        }
+        layer
+    }
+
+    /// The smallest `(x, y, width, height)` rectangle holding every non-air tile
+    pub fn used_bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+        for (y, row) in self.rows().enumerate() {
+            for (x, chunk) in row.iter().enumerate() {
+                if *chunk == ChunkType::AIR {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    Some((x0, y0, x1, y1)) => (x0.min(x), y0.min(y), x1.max(x), y1.max(y)),
+                    None => (x, y, x, y),
+                });
+            }
+        }
+        bounds.map(|(x0, y0, x1, y1)| (x0, y0, x1 - x0 + 1, y1 - y0 + 1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[ChunkType]> {
+        self.tiles.chunks_exact(self.width)
+    }
+}
+
+impl Index<usize> for Layer {
+    type Output = [ChunkType];
+
+    fn index(&self, y: usize) -> &[ChunkType] {
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl IndexMut<usize> for Layer {
+    fn index_mut(&mut self, y: usize) -> &mut [ChunkType] {
+        &mut self.tiles[y * self.width..(y + 1) * self.width]
+    }
+}
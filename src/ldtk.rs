@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde_json::{json, Value};
+use crate::history::Snapshot;
+use crate::images::Image;
+use crate::layer::Layer;
+use crate::tiles::TileSet;
+use crate::{save, ChunkType, SaveError, CHUNK_SIZE};
+
+/// The LDtk release the written project matches
+const LDTK_VERSION: &str = "1.5.3";
+
+// Uids of the definitions, LDtk only needs them to be unique
+const FOREGROUND_UID:  u32 = 1;
+const BACKGROUND_UID:  u32 = 2;
+const FG_TILES_UID:    u32 = 3;
+const BG_TILES_UID:    u32 = 4;
+const ENTITIES_UID:    u32 = 5;
+const SPAWN_UID:       u32 = 6;
+const CHECKPOINT_UID:  u32 = 7;
+const TILESET_UID:     u32 = 8;
+const NEXT_UID:        u32 = 9;
+
+/// Hands out the random looking ids LDtk gives every instance
+struct Iids(u64);
+
+impl Iids {
+    fn new() -> Self {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64);
+        Iids(seed)
+    }
+
+    /// A version 4 UUID from a splitmix64 sequence
+    fn next(&mut self) -> String {
+        let mut half = || {
+            self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        let (a, b) = (half(), half());
+        format!("{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+            a >> 32, (a >> 16) & 0xffff, a & 0xfff, 0x8000 | (b >> 48) & 0x3fff, b & 0xffff_ffff_ffff)
+    }
+}
+
+/// Writes the level as an LDtk project at `path`: both layers as IntGrid layers holding tile ids
+/// with Tile layers showing the art on top, and the spawn and checkpoints as entities.
+/// The tileset picture goes next to it as `<name>_tileset.png`.
+pub fn export(level: &Snapshot, tiles: &TileSet, path: &Path) -> Result<(), SaveError> {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let atlas_path = path.with_file_name(format!("{}_tileset.png", stem));
+    let atlas = tiles.atlas();
+    atlas.save(&atlas_path).map_err(|e| SaveError::Image(atlas_path.clone(), e))?;
+
+    let size = CHUNK_SIZE as usize;
+    let columns = tiles.atlas_columns();
+    let cells: HashMap<ChunkType, usize> = tiles.blocks().enumerate().map(|(i, t)| (ChunkType(t.def.id), i)).collect();
+    let (width, height) = (level.chunks[0].width(), level.chunks[0].height());
+    let mut iids = Iids::new();
+
+    let values: Vec<Value> = tiles.blocks()
+        .map(|tile| json!({
+            "value": tile.def.id,
+            "identifier": identifier(&tile.def.name),
+            "color": average_color(&tile.image),
+            "tile": tile_rect(cells[&ChunkType(tile.def.id)], columns),
+            "groupUid": 0,
+        }))
+        .collect();
+    let layer_defs = vec![
+        layer_def("Entities", "Entities", ENTITIES_UID, &[], None),
+        layer_def("ForegroundTiles", "Tiles", FG_TILES_UID, &[], Some(TILESET_UID)),
+        layer_def("Foreground", "IntGrid", FOREGROUND_UID, &values, None),
+        layer_def("BackgroundTiles", "Tiles", BG_TILES_UID, &[], Some(TILESET_UID)),
+        layer_def("Background", "IntGrid", BACKGROUND_UID, &values, None),
+    ];
+    let entity_defs = vec![
+        entity_def("Spawn", SPAWN_UID, "#FF0000", 1),
+        entity_def("Checkpoint", CHECKPOINT_UID, "#FFE761", 0),
+    ];
+    let tileset_def = json!({
+        "__cWid": columns,
+        "__cHei": atlas.height / size,
+        "identifier": "Tiles",
+        "uid": TILESET_UID,
+        "relPath": atlas_path.file_name().unwrap_or_default().to_string_lossy(),
+        "embedAtlas": null,
+        "pxWid": atlas.width,
+        "pxHei": atlas.height,
+        "tileGridSize": size,
+        "spacing": 0,
+        "padding": 0,
+        "tags": [],
+        "tagsSourceEnumUid": null,
+        "enumTags": [],
+        "customData": [],
+        "savedSelections": [],
+        "cachedPixelData": null,
+    });
+
+    let mut entities = Vec::new();
+    let (spawn_y, spawn_x) = level.spawn_chunk;
+    entities.push(entity("Spawn", SPAWN_UID, "#FF0000", spawn_x, spawn_y, iids.next()));
+    for (y, row) in level.chunks[0].rows().enumerate() {
+        for (x, chunk) in row.iter().enumerate() {
+            if tiles.get(*chunk).is_some_and(|t| t.def.checkpoint) {
+                entities.push(entity("Checkpoint", CHECKPOINT_UID, "#FFE761", x, y, iids.next()));
+            }
+        }
+    }
+
+    let grid = |layer: &Layer| -> Vec<u16> { layer.rows().flatten().map(|c| c.0).collect() };
+    let grid_tiles = |layer: &Layer| -> Vec<Value> {
+        layer.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, chunk)| (x, y, *chunk)))
+            .filter_map(|(x, y, chunk)| cells.get(&chunk).map(|&cell| json!({
+                "px": [x * size, y * size],
+                "src": [cell % columns * size, cell / columns * size],
+                "f": 0,
+                "t": cell,
+                "d": [y * width + x],
+                "a": 1,
+            })))
+            .collect()
+    };
+    let layer_instances = vec![
+        layer_instance("Entities", "Entities", ENTITIES_UID, width, height, iids.next(), json!({ "entityInstances": entities })),
+        layer_instance("ForegroundTiles", "Tiles", FG_TILES_UID, width, height, iids.next(), json!({ "gridTiles": grid_tiles(&level.chunks[0]) })),
+        layer_instance("Foreground", "IntGrid", FOREGROUND_UID, width, height, iids.next(), json!({ "intGridCsv": grid(&level.chunks[0]) })),
+        layer_instance("BackgroundTiles", "Tiles", BG_TILES_UID, width, height, iids.next(), json!({ "gridTiles": grid_tiles(&level.chunks[1]) })),
+        layer_instance("Background", "IntGrid", BACKGROUND_UID, width, height, iids.next(), json!({ "intGridCsv": grid(&level.chunks[1]) })),
+    ];
+
+    let name = if level.meta.name.is_empty() { stem.to_string() } else { level.meta.name.clone() };
+    let header = json!({
+        "fileType": "LDtk Project JSON",
+        "app": "LDtk",
+        "doc": "https://ldtk.io/json",
+        "schema": "https://ldtk.io/files/JSON_SCHEMA.json",
+        "appAuthor": "Sebastien 'deepnight' Benard",
+        "appVersion": LDTK_VERSION,
+        "url": "https://ldtk.io",
+    });
+    let defs = json!({
+        "layers": layer_defs,
+        "entities": entity_defs,
+        "tilesets": [tileset_def],
+        "enums": [],
+        "externalEnums": [],
+        "levelFields": [],
+    });
+    let ldtk_level = json!({
+        "identifier": identifier(&name),
+        "iid": iids.next(),
+        "uid": 0,
+        "worldX": 0,
+        "worldY": 0,
+        "worldDepth": 0,
+        "pxWid": width * size,
+        "pxHei": height * size,
+        "__bgColor": "#696A79",
+        "bgColor": null,
+        "useAutoIdentifier": false,
+        "bgRelPath": null,
+        "bgPos": null,
+        "bgPivotX": 0.5,
+        "bgPivotY": 0.5,
+        "__smartColor": "#ADADB5",
+        "__bgPos": null,
+        "externalRelPath": null,
+        "fieldInstances": [],
+        "layerInstances": layer_instances,
+        "__neighbours": [],
+    });
+    let project = json!({
+        "__header__": header,
+        "iid": iids.next(),
+        "jsonVersion": LDTK_VERSION,
+        "appBuildId": 0,
+        "nextUid": NEXT_UID,
+        "identifierStyle": "Capitalize",
+        "toc": [],
+        "worldLayout": "Free",
+        "worldGridWidth": width * size,
+        "worldGridHeight": height * size,
+        "defaultLevelWidth": width * size,
+        "defaultLevelHeight": height * size,
+        "defaultPivotX": 0,
+        "defaultPivotY": 0,
+        "defaultGridSize": size,
+        "defaultEntityWidth": size,
+        "defaultEntityHeight": size,
+        "bgColor": "#40465B",
+        "defaultLevelBgColor": "#696A79",
+        "minifyJson": false,
+        "externalLevels": false,
+        "exportTiled": false,
+        "simplifiedExport": false,
+        "imageExportMode": "None",
+        "exportLevelBg": true,
+        "pngFilePattern": null,
+        "backupOnSave": false,
+        "backupLimit": 10,
+        "backupRelPath": null,
+        "levelNamePattern": "Level_%idx",
+        "tutorialDesc": null,
+        "customCommands": [],
+        "flags": [],
+        "defs": defs,
+        "levels": [ldtk_level],
+        "worlds": [],
+        "dummyWorldIid": iids.next(),
+    });
+
+    save::write_file(path, 0, |file| save::write_json(file, &project))
+}
+
+fn layer_def(identifier: &str, kind: &str, uid: u32, values: &[Value], tileset: Option<u32>) -> Value {
+    json!({
+        "__type": kind,
+        "identifier": identifier,
+        "type": kind,
+        "uid": uid,
+        "doc": null,
+        "uiColor": null,
+        "gridSize": CHUNK_SIZE,
+        "guideGridWid": 0,
+        "guideGridHei": 0,
+        "displayOpacity": 1,
+        "inactiveOpacity": 1,
+        "hideInList": false,
+        "hideFieldsWhenInactive": true,
+        "canSelectWhenInactive": true,
+        "renderInWorldView": true,
+        "pxOffsetX": 0,
+        "pxOffsetY": 0,
+        "parallaxFactorX": 0,
+        "parallaxFactorY": 0,
+        "parallaxScaling": true,
+        "requiredTags": [],
+        "excludedTags": [],
+        "autoTilesKilledByOtherLayerUid": null,
+        "uiFilterTags": [],
+        "useAsyncRender": false,
+        "intGridValues": values,
+        "intGridValuesGroups": [],
+        "autoRuleGroups": [],
+        "autoSourceLayerDefUid": null,
+        "tilesetDefUid": tileset,
+        "tilePivotX": 0,
+        "tilePivotY": 0,
+        "biomeFieldUid": null,
+    })
+}
+
+/// `max_count` of 0 allows any number per level
+fn entity_def(identifier: &str, uid: u32, color: &str, max_count: usize) -> Value {
+    json!({
+        "identifier": identifier,
+        "uid": uid,
+        "tags": [],
+        "exportToToc": false,
+        "allowOutOfBounds": false,
+        "doc": null,
+        "width": CHUNK_SIZE,
+        "height": CHUNK_SIZE,
+        "resizableX": false,
+        "resizableY": false,
+        "minWidth": null,
+        "maxWidth": null,
+        "minHeight": null,
+        "maxHeight": null,
+        "keepAspectRatio": false,
+        "tileOpacity": 1,
+        "fillOpacity": 0.5,
+        "lineOpacity": 1,
+        "hollow": false,
+        "color": color,
+        "renderMode": "Rectangle",
+        "showName": true,
+        "tilesetId": null,
+        "tileRenderMode": "FitInside",
+        "tileRect": null,
+        "uiTileRect": null,
+        "nineSliceBorders": [],
+        "maxCount": max_count,
+        "limitScope": "PerLevel",
+        "limitBehavior": "MoveLastOne",
+        "pivotX": 0,
+        "pivotY": 0,
+        "fieldDefs": [],
+    })
+}
+
+fn entity(identifier: &str, uid: u32, color: &str, x: usize, y: usize, iid: String) -> Value {
+    let size = CHUNK_SIZE as usize;
+    json!({
+        "__identifier": identifier,
+        "__grid": [x, y],
+        "__pivot": [0, 0],
+        "__tags": [],
+        "__tile": null,
+        "__smartColor": color,
+        "__worldX": x * size,
+        "__worldY": y * size,
+        "iid": iid,
+        "width": size,
+        "height": size,
+        "defUid": uid,
+        "px": [x * size, y * size],
+        "fieldInstances": [],
+    })
+}
+
+/// A layer of the level, `contents` fills in the part that depends on its type
+fn layer_instance(identifier: &str, kind: &str, uid: u32, width: usize, height: usize, iid: String, contents: Value) -> Value {
+    let tiles = kind == "Tiles";
+    let mut layer = json!({
+        "__identifier": identifier,
+        "__type": kind,
+        "__cWid": width,
+        "__cHei": height,
+        "__gridSize": CHUNK_SIZE,
+        "__opacity": 1,
+        "__pxTotalOffsetX": 0,
+        "__pxTotalOffsetY": 0,
+        "__tilesetDefUid": if tiles { Some(TILESET_UID) } else { None },
+        "__tilesetRelPath": null,
+        "iid": iid,
+        "levelId": 0,
+        "layerDefUid": uid,
+        "pxOffsetX": 0,
+        "pxOffsetY": 0,
+        "visible": true,
+        "optionalRules": [],
+        "intGridCsv": [],
+        "autoLayerTiles": [],
+        "seed": 0,
+        "overrideTilesetUid": null,
+        "gridTiles": [],
+        "entityInstances": [],
+    });
+    if let (Some(layer), Value::Object(contents)) = (layer.as_object_mut(), contents) {
+        layer.extend(contents);
+    }
+    layer
+}
+
+fn tile_rect(cell: usize, columns: usize) -> Value {
+    let size = CHUNK_SIZE as usize;
+    json!({ "tilesetUid": TILESET_UID, "x": cell % columns * size, "y": cell / columns * size, "w": size, "h": size })
+}
+
+/// LDtk identifiers start with a capital and only hold letters, digits and underscores
+fn identifier(name: &str) -> String {
+    let mut identifier: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if !identifier.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        identifier.insert(0, 'L');
+    }
+    identifier[..1].to_ascii_uppercase() + &identifier[1..]
+}
+
+/// The mean colour of the tile's visible pixels, as `#rrggbb`
+fn average_color(image: &Image) -> String {
+    let visible: Vec<&[u8; 4]> = image.iter().flatten().filter(|p| p[3] != 0).collect();
+    let count = visible.len().max(1) as u32;
+    let channel = |i: usize| visible.iter().map(|p| p[i] as u32).sum::<u32>() / count;
+    format!("#{:02X}{:02X}{:02X}", channel(0), channel(1), channel(2))
+}
@@ -1,24 +1,65 @@
 #![allow(dead_code)]
 
+mod block_editor;
+mod cli;
+mod format;
+mod history;
 mod images;
+mod import;
+mod layer;
+mod ldtk;
+mod meta;
+mod project;
+mod prompt;
+mod render;
+mod save;
+mod tiled;
+mod tiles;
+mod tools;
+mod ui;
 
-use images::*;
-use std::fs::{File, self};
-use std::io::Write;
+use block_editor::{BlockAction, BlockEditor};
+use format::MapFormat;
+use history::{History, Snapshot};
+use images::ImageError;
+use import::Palette;
+use layer::Layer;
+use meta::{LevelMeta, MetaPanel};
+use project::Project;
+use prompt::{Answer, Prompt};
+use render::{image_pixels, ImageLayers, ImageOptions};
+use tiles::{TileDef, TileError, TileSet};
+use tools::Tool;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use clap::Parser;
 use log::error;
+use serde::{Deserialize, Serialize};
 use pixels::{Error, Pixels, SurfaceTexture};
 use winit::dpi::LogicalSize;
 use winit::event::{Event, VirtualKeyCode};
 use winit::event_loop::{ControlFlow, EventLoop};
-use winit::window::WindowBuilder;
+use winit::window::{Window, WindowBuilder};
 use winit_input_helper::WinitInputHelper;
 
 //Config
 const WIDTH:         u32 = 960;
 const HEIGHT:        u32 = 736;
 const CHUNK_SIZE:    u32 = 32;
-const SIM_WIDTH:     u32 = 1280;
-const SIM_HEIGHT:    u32 = 960;
+/// Zoom levels in percent, a tile is `CHUNK_SIZE * zoom / 100` pixels on screen
+const ZOOM_LEVELS:   [usize; 5] = [25, 50, 100, 200, 400];
+const DEFAULT_ZOOM:  usize = 2;
+const HISTORY_SIZE:  usize = 100;
+/// How long a status message stays at the bottom of the window
+const STATUS_TIME:   Duration = Duration::from_secs(5);
+/// How often unsaved changes are written to the recovery files
+const AUTOSAVE_TIME: Duration = Duration::from_secs(60);
+/// Size of a tile in the game's own pixels, which is what map.json positions are measured in
+const GAME_TILE:     usize = 16;
 
 //Color
 const WHITE:  &[u8; 4] = &[0xff, 0xff, 0xff, 0xff];
@@ -27,56 +68,269 @@ const RED:    &[u8; 4] = &[0xff, 0x00, 0x00, 0xff];
 const SELECT: &[u8; 4] = &[0xff, 0xff, 0xff, 0x64];
 
 //Paths
-const EXPORT:     &str = "map.txt";
-const EXPORT_BG:  &str = "map_bg.txt";
-const EX_JSON:    &str = "map.json";
-
-#[derive(Clone, Copy, PartialEq)]
-enum ChunkType {
-    Air,
-    Dirt,
-    Grass,
-    CheckPoint,
-    Spikes,
-    TallGrass,
-    Stone,
-    Planks,
-    CrackedStone
+const BG_SUFFIX:  &str = "_bg";
+const JSON_EXT:   &str = "json";
+const TILES_FILE: &str = "tiles.json";
+const PROJECT_EXT: &str = "trumptor";
+const PNG_EXT:     &str = "png";
+const TMX_EXT:     &str = "tmx";
+const LDTK_EXT:    &str = "ldtk";
+const RECOVERY_SUFFIX: &str = ".recovery";
+
+/// The id of a tile in the `TileSet`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ChunkType(pub u16);
+
+impl ChunkType {
+    pub const AIR: ChunkType = ChunkType(0);
 }
 
-impl From<u8> for ChunkType {
-    fn from(n: u8) -> Self {
-        match n {
-            1 => ChunkType::Dirt,
-            2 => ChunkType::Grass,
-            3 => ChunkType::CheckPoint,
-            4 => ChunkType::Spikes,
-            5 => ChunkType::TallGrass,
-            6 => ChunkType::Stone,
-            7 => ChunkType::Planks,
-            8 => ChunkType::CrackedStone,
-            _ => ChunkType::Air
+#[derive(Debug)]
+enum LoadError {
+    Io(PathBuf, io::Error),
+    MalformedRow { path: PathBuf, line: usize, column: usize, found: String },
+    UnsupportedVersion { path: PathBuf, version: String },
+    MalformedPixels { path: PathBuf, tile: String },
+    Image(PathBuf, ImageError),
+    BadColor { path: PathBuf, color: String },
+    UnknownColor { path: PathBuf, line: usize, column: usize, color: String },
+    UnknownTileName { path: PathBuf, name: String },
+    Xml(PathBuf, roxmltree::Error),
+    Tiled { path: PathBuf, message: String },
+    WrongWidth { path: PathBuf, line: usize, expected: usize, found: usize },
+    WrongHeight { path: PathBuf, expected: usize, found: usize },
+    InvalidSize { path: PathBuf, width: usize, height: usize },
+    UnknownTile { path: PathBuf, line: usize, column: usize, id: u16 },
+    Json(PathBuf, serde_json::Error),
+    Tiles(TileError),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            LoadError::MalformedRow { path, line, column, found } =>
+                write!(f, "{}:{}:{}: expected a tile id, found {:?}", path.display(), line, column, found),
+            LoadError::UnsupportedVersion { path, version } =>
+                write!(f, "{}: unsupported format version {:?}", path.display(), version),
+            LoadError::MalformedPixels { path, tile } =>
+                write!(f, "{}: the art of tile {:?} is not 32 rows of 32 rrggbbaa pixels", path.display(), tile),
+            LoadError::Image(path, e) => write!(f, "{}: {}", path.display(), e),
+            LoadError::BadColor { path, color } =>
+                write!(f, "{}: {:?} is not a #rrggbb colour", path.display(), color),
+            LoadError::UnknownColor { path, line, column, color } =>
+                write!(f, "{}:{}:{}: no tile has the colour {}", path.display(), line, column, color),
+            LoadError::UnknownTileName { path, name } =>
+                write!(f, "{}: there is no tile {:?}", path.display(), name),
+            LoadError::Xml(path, e) => write!(f, "{}: {}", path.display(), e),
+            LoadError::Tiled { path, message } => write!(f, "{}: {}", path.display(), message),
+            LoadError::WrongWidth { path, line, expected, found } =>
+                write!(f, "{}:{}: row is {} tiles wide, expected {}", path.display(), line, found, expected),
+            LoadError::WrongHeight { path, expected, found } =>
+                write!(f, "{}: map is {} rows high, expected {}", path.display(), found, expected),
+            LoadError::InvalidSize { path, width, height } =>
+                write!(f, "{}: map is {}x{} tiles, a level needs at least one tile", path.display(), width, height),
+            LoadError::UnknownTile { path, line, column, id } =>
+                write!(f, "{}:{}:{}: unknown tile id {}", path.display(), line, column, id),
+            LoadError::Json(path, e) => write!(f, "{}: {}", path.display(), e),
+            LoadError::Tiles(e) => write!(f, "{}", e),
         }
     }
 }
 
+impl std::error::Error for LoadError {}
+
+#[derive(Debug)]
+enum SaveError {
+    Io(PathBuf, io::Error),
+    IdTooLarge(u16),
+    Image(PathBuf, ImageError),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            SaveError::Image(path, e) => write!(f, "{}: {}", path.display(), e),
+            SaveError::IdTooLarge(id) =>
+                write!(f, "tile id {} does not fit in the single digit map format, save with --format v2", id),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+/// The contents of map.json, the spawn in game pixels followed by the level metadata
+#[derive(Serialize, Deserialize)]
+struct MapJson {
+    x: usize,
+    y: usize,
+    #[serde(flatten)]
+    meta: LevelMeta,
+}
+
+/// The three files a level is exported to, and the project it is saved in
+#[derive(Clone)]
+struct MapPaths {
+    map: PathBuf,
+    bg: PathBuf,
+    json: PathBuf,
+    project: PathBuf,
+}
+
+impl MapPaths {
+    /// Derives `<name>_bg.txt`, `<name>.json` and `<name>.trumptor` from `<name>.txt`
+    fn new(map: &Path) -> Self {
+        let stem = map.file_stem().unwrap_or_default().to_string_lossy();
+        let mut bg = format!("{}{}", stem, BG_SUFFIX);
+        if let Some(ext) = map.extension() {
+            bg = format!("{}.{}", bg, ext.to_string_lossy());
+        }
+        MapPaths {
+            map: map.to_path_buf(),
+            bg: map.with_file_name(bg),
+            json: map.with_extension(JSON_EXT),
+            project: map.with_extension(PROJECT_EXT),
+        }
+    }
+
+    /// Where autosaves of the level go, a project named `<name>.trumptor.recovery`
+    fn recovery(&self) -> PathBuf {
+        save::with_suffix(&self.project, RECOVERY_SUFFIX)
+    }
+
+    /// Whether the project was saved more recently than the level was exported, or is all there is
+    fn project_newer(&self) -> bool {
+        match (modified_time(&self.project), modified_time(&self.map)) {
+            (Some(project), Some(map)) => project >= map,
+            (project, _) => project.is_some(),
+        }
+    }
+
+    /// Whether there is an autosave written after the level was last saved or exported
+    fn recovery_newer(&self) -> bool {
+        let saved = modified_time(&self.project).max(modified_time(&self.map));
+        modified_time(&self.recovery()) > saved
+    }
+
+    fn remove_recovery(&self) {
+        let recovery = self.recovery();
+        if let Err(e) = fs::remove_file(&recovery) {
+            if e.kind() != io::ErrorKind::NotFound {
+                error!("failed to remove {}: {}", recovery.display(), e);
+            }
+        }
+    }
+
+    /// The tile definitions kept next to the level, where new blocks are saved to
+    fn tiles(&self) -> PathBuf {
+        self.map.with_file_name(TILES_FILE)
+    }
+}
+
 struct World {
-    chunks: [[[ChunkType; (SIM_WIDTH/CHUNK_SIZE) as usize]; (SIM_HEIGHT/CHUNK_SIZE) as usize]; 2],
+    paths: MapPaths,
+    tiles: TileSet,
+    chunks: [Layer; 2],
     chunk_mode: usize,
+    /// Scroll position in world pixels, as `(y, x)`
     offset: (usize, usize),
-    tmp_chunk: (usize, usize),
+    /// Index into `ZOOM_LEVELS`
+    zoom: usize,
+    /// Mouse position in frame pixels, as `(y, x)`
+    cursor: (usize, usize),
+    /// Size of the frame in pixels, it follows the window
+    view_width: usize,
+    view_height: usize,
+    /// Tile the player spawns on, as `(y, x)` in world tiles
     spawn_chunk: (usize, usize),
-    chunk_type: ChunkType
+    chunk_type: ChunkType,
+    tool: Tool,
+    drag_start: Option<(usize, usize)>,
+    block_editor: Option<BlockEditor>,
+    meta: LevelMeta,
+    meta_panel: Option<MetaPanel>,
+    /// A message shown at the bottom of the window, such as the result of the last save
+    status: Option<(String, Instant)>,
+    /// How many old versions of each file saving keeps
+    backups: usize,
+    /// Whether the level changed since it was last saved or loaded
+    modified: bool,
+    /// The format the map files are saved in, the one they were loaded from unless picked with --format
+    format: MapFormat,
+    /// How Ctrl+E renders the level to an image
+    image_options: ImageOptions,
+    /// The level as it was last autosaved, if it was since the last save
+    autosaved: Option<Snapshot>,
+    last_autosave: Instant,
+    prompt: Option<Prompt>,
+    last_placed: Option<(usize, usize)>,
+    fill_masked: bool,
+    history: History
 }
 
 fn main() -> Result<(), Error> {
     env_logger::init();
+    let args = cli::Args::parse();
+    let paths = MapPaths::new(&args.map_path());
+    let tiles = match &args.tiles {
+        Some(path) => TileSet::load(path),
+        None if paths.tiles().exists() => TileSet::load(&paths.tiles()),
+        None => Ok(TileSet::builtin()),
+    };
+    let tiles = match tiles {
+        Ok(tiles) => tiles,
+        Err(e) => {
+            error!("failed to load tiles: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let world = match (&args.import, &args.open) {
+        (Some(image), _) => World::import(paths, tiles, image, args.import_bg.as_deref(), args.palette.as_deref()),
+        (None, Some(open)) => World::open(&MapPaths::new(open), tiles).map(|w| w.with_paths(paths)),
+        (None, None) if paths.map.exists() || paths.project.exists() => World::open(&paths, tiles),
+        (None, None) => World::new(paths, tiles, args.width, args.height),
+    };
+    let mut world = match world {
+        Ok(world) => world.with_backups(args.backups),
+        Err(e) => {
+            error!("failed to open level: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Some(format) = args.format {
+        world.format = format;
+    }
+    world.image_options = ImageOptions { layers: args.image_layers, scale: args.image_scale };
+    if let Some(path) = &args.export_image {
+        if let Err(e) = world.export_image(path, world.image_options) {
+            error!("failed to export image: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    if let Some(path) = &args.export_tmx {
+        if let Err(e) = create_parent(path).and_then(|()| tiled::export(&world.snapshot(), &world.tiles, path)) {
+            error!("failed to export Tiled map: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    if let Some(path) = &args.export_ldtk {
+        if let Err(e) = create_parent(path).and_then(|()| ldtk::export(&world.snapshot(), &world.tiles, path)) {
+            error!("failed to export LDtk project: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    world.check_recovery();
+
     let event_loop = EventLoop::new();
     let mut input = WinitInputHelper::new();
     let window = {
         let size = LogicalSize::new(WIDTH as f64, HEIGHT as f64);
         WindowBuilder::new()
-            .with_title("Hello Pixels")
+            .with_title(world.title())
             .with_inner_size(size)
             .with_min_inner_size(size)
             .build(&event_loop)
@@ -86,10 +340,12 @@ fn main() -> Result<(), Error> {
     let mut pixels = {
         let window_size = window.inner_size();
         let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, &window);
-        Pixels::new(WIDTH, HEIGHT, surface_texture)?
+        let (width, height) = buffer_size(&window);
+        world.resize_view(width, height);
+        Pixels::new(width, height, surface_texture)?
     };
-    let mut world = World::new();
 
+    let mut title = world.title();
     event_loop.run(move |event, _, control_flow| {
         if let Event::RedrawRequested(_) = event {
             world.draw(pixels.get_frame());
@@ -104,34 +360,112 @@ fn main() -> Result<(), Error> {
         }
 
         if input.update(&event) {
-            if input.key_pressed(VirtualKeyCode::Escape) || input.quit() {
+            let quit = input.quit() || (input.key_pressed(VirtualKeyCode::Escape) && !world.in_panel());
+            let exit = if quit { world.quit_requested(input.quit()) } else { world.answer_prompt(&input) };
+            if exit {
                 *control_flow = ControlFlow::Exit;
                 return;
             }
 
+            // Minimised windows report a size of zero, keep the old frame until they come back
+            let size = window.inner_size();
+            let resized = input.window_resized().is_some() || input.scale_factor_changed().is_some();
+            if resized && size.width > 0 && size.height > 0 {
+                let (width, height) = buffer_size(&window);
+                pixels.resize_surface(size.width, size.height);
+                pixels.resize_buffer(width, height);
+                world.resize_view(width, height);
+            }
+
+            // The window reports physical pixels, map them onto the frame
+            let mouse = input.mouse().map(|pos| {
+                let (x, y) = pixels.window_pos_to_pixel(pos).unwrap_or_else(|pos| pixels.clamp_pixel_pos(pos));
+                (x as f32, y as f32)
+            });
+
+            world.autosave();
+
+            if world.title() != title {
+                title = world.title();
+                window.set_title(&title);
+            }
+
+            if world.prompt.is_some() {
+                window.request_redraw();
+                return;
+            }
+
+            if world.block_editor.is_some() {
+                world.edit_block(input.clone(), mouse);
+                window.request_redraw();
+                return;
+            }
+
+            if world.meta_panel.is_some() {
+                world.edit_meta(input.clone(), mouse);
+                window.request_redraw();
+                return;
+            }
+
+            if input.key_pressed(VirtualKeyCode::K) {
+                world.open_block_editor();
+            }
+
+            if input.key_pressed(VirtualKeyCode::Tab) {
+                world.open_meta_panel();
+            }
+
             if input.key_pressed(VirtualKeyCode::Return) {
-                world.save();
+                world.export_with_status();
+            }
+
+            if input.held_control() && input.key_pressed(VirtualKeyCode::S) {
+                world.save_with_status();
+            }
+
+            if input.held_control() && input.key_pressed(VirtualKeyCode::E) {
+                world.export_image_with_status();
+            }
+
+            if input.held_control() && input.key_pressed(VirtualKeyCode::T) {
+                world.export_tiled_with_status();
+            }
+
+            if input.held_control() && input.key_pressed(VirtualKeyCode::L) {
+                world.export_ldtk_with_status();
+            }
+
+            if input.held_control() && input.key_pressed(VirtualKeyCode::I) {
+                world.import_tiled_with_status();
+            }
+
+            if input.mouse_pressed(0) {
+                world.begin_stroke(input.held_shift());
             }
 
             if input.mouse_held(0) {
                 world.imprint();
             }
 
+            if input.mouse_released(0) {
+                world.end_stroke();
+            }
+
             if input.mouse_pressed(1) {
-                world.set_spawn(input.clone());
+                world.set_spawn();
             }
 
             if input.mouse_pressed(2) {
                 world.set_chunk_mode();
             }
 
-            if let Some(size) = input.window_resized() {
-                pixels.resize_surface(size.width, size.height);
-            }
-
+            world.set_history(input.clone());
+            world.set_size(input.clone());
             world.set_offset(input.clone());
+            world.set_zoom(input.clone());
             world.set_chunk_type(input.clone());
-            world.update(input.clone());
+            world.set_tool(input.clone());
+            world.update(mouse);
 
             window.request_redraw();
         }
@@ -139,183 +473,859 @@ fn main() -> Result<(), Error> {
 }
 
 impl World {
-    fn new() -> Self {
-        World {
-            chunks: [[[ChunkType::Air; (SIM_WIDTH/CHUNK_SIZE) as usize]; (SIM_HEIGHT/CHUNK_SIZE) as usize]; 2],
+    fn new(paths: MapPaths, tiles: TileSet, width: usize, height: usize) -> Result<Self, LoadError> {
+        if width == 0 || height == 0 {
+            return Err(LoadError::InvalidSize { path: paths.map, width, height });
+        }
+        Ok(World {
+            paths,
+            chunk_type: tiles.first_block(),
+            tiles,
+            chunks: [Layer::new(width, height), Layer::new(width, height)],
             chunk_mode: 0,
             offset: (0, 0),
-            tmp_chunk: (0, 0),
+            zoom: DEFAULT_ZOOM,
+            cursor: (0, 0),
+            view_width: WIDTH as usize,
+            view_height: HEIGHT as usize,
             spawn_chunk: (0, 0),
-            chunk_type: ChunkType::Dirt
+            tool: Tool::Pencil,
+            drag_start: None,
+            block_editor: None,
+            meta: LevelMeta::default(),
+            meta_panel: None,
+            status: None,
+            backups: 1,
+            modified: false,
+            format: MapFormat::Legacy,
+            image_options: ImageOptions { layers: ImageLayers::Both, scale: 1.0 },
+            autosaved: None,
+            last_autosave: Instant::now(),
+            prompt: None,
+            last_placed: None,
+            fill_masked: true,
+            history: History::new(HISTORY_SIZE)
+        })
+    }
+
+    fn width(&self) -> usize {
+        self.chunks[0].width()
+    }
+
+    fn height(&self) -> usize {
+        self.chunks[0].height()
+    }
+
+    fn with_paths(mut self, paths: MapPaths) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// The window title: level name, where it is saved and a * while there are unsaved changes
+    fn title(&self) -> String {
+        let name = if self.meta.name.is_empty() {
+            self.paths.map.file_stem().unwrap_or_default().to_string_lossy().into_owned()
+        } else {
+            self.meta.name.clone()
+        };
+        let marker = if self.modified { "*" } else { "" };
+        format!("{}{} - {} - trumptor", marker, name, self.paths.map.display())
+    }
+
+    fn with_backups(mut self, backups: usize) -> Self {
+        self.backups = backups;
+        self
+    }
+
+    fn update(&mut self, mouse: Option<(f32, f32)>) {
+        if let Some((x, y)) = mouse {
+            self.cursor = (y as usize, x as usize);
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            chunks: self.chunks.clone(),
+            spawn_chunk: self.spawn_chunk,
+            chunk_mode: self.chunk_mode,
+            meta: self.meta.clone(),
         }
     }
 
-    fn update(&mut self, input: WinitInputHelper) {
-        if let Some((x, y)) = input.mouse() {
-            self.tmp_chunk = (y as usize/CHUNK_SIZE as usize, x as usize/CHUNK_SIZE as usize);
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.chunks = snapshot.chunks;
+        self.spawn_chunk = snapshot.spawn_chunk;
+        self.chunk_mode = snapshot.chunk_mode;
+        self.meta = snapshot.meta;
+        self.clamp_offset();
+    }
+
+    /// Applies a single undoable edit
+    fn edit(&mut self, f: impl FnOnce(&mut Self)) {
+        let before = self.snapshot();
+        f(self);
+        let after = self.snapshot();
+        self.modified |= !before.same_level(&after);
+        self.history.record(before, &after);
+    }
+
+    /// Starts a left mouse stroke, with `connect` a pencil stroke continues from the last placed tile
+    fn begin_stroke(&mut self, connect: bool) {
+        let before = self.snapshot();
+        self.history.begin_stroke(before);
+        if !connect {
+            self.last_placed = None;
+        }
+        if self.tool == Tool::Fill {
+            if let Some(start) = self.hovered() {
+                self.bucket_fill(start);
+            }
         }
     }
 
+    /// Applies a dragged rectangle or line, releasing the mouse outside the level drops it
+    fn end_stroke(&mut self) {
+        if let (Some(start), Some(end)) = (self.drag_start.take(), self.hovered()) {
+            if self.tool == Tool::Line {
+                for (y, x) in tools::line(start, end) {
+                    self.place(x, y, self.chunk_type);
+                }
+                self.last_placed = Some(end);
+            } else {
+                let chunk = if self.tool == Tool::RectErase { ChunkType::AIR } else { self.chunk_type };
+                let (y0, x0, y1, x1) = tools::rect(start, end);
+                for y in y0..=y1 {
+                    for x in x0..=x1 {
+                        self.place(x, y, chunk);
+                    }
+                }
+            }
+        }
+        let after = self.snapshot();
+        self.history.end_stroke(&after);
+    }
+
+    /// Ctrl+Z undoes the last edit, Ctrl+Y or Ctrl+Shift+Z redoes it
+    fn set_history(&mut self, input: WinitInputHelper) {
+        if !input.held_control() {
+            return;
+        }
+        let redo = input.key_pressed(VirtualKeyCode::Y) ||
+            (input.held_shift() && input.key_pressed(VirtualKeyCode::Z));
+        let snapshot = if redo {
+            self.history.redo(self.snapshot())
+        } else if input.key_pressed(VirtualKeyCode::Z) {
+            self.history.undo(self.snapshot())
+        } else {
+            None
+        };
+        if let Some(snapshot) = snapshot {
+            self.modified |= !snapshot.same_level(&self.snapshot());
+            self.restore(snapshot);
+        }
+    }
+
+    /// Converts a frame pixel to a world pixel, as `(y, x)`
+    fn to_world(&self, y: usize, x: usize) -> (usize, usize) {
+        let zoom = ZOOM_LEVELS[self.zoom];
+        (self.offset.0 + y * 100 / zoom, self.offset.1 + x * 100 / zoom)
+    }
+
+    /// The tile under the cursor in world coordinates, as `(y, x)`, or `None` past the edge of the level
+    fn hovered(&self) -> Option<(usize, usize)> {
+        let (y, x) = self.to_world(self.cursor.0, self.cursor.1);
+        let (y, x) = (y / CHUNK_SIZE as usize, x / CHUNK_SIZE as usize);
+        (y < self.height() && x < self.width()).then_some((y, x))
+    }
+
     fn imprint(&mut self) {
-        let (mut y,mut x) = self.tmp_chunk;
-        x += self.offset.1 / CHUNK_SIZE as usize;
-        y += self.offset.0 / CHUNK_SIZE as usize;
-        x = x.clamp(0, (SIM_WIDTH/CHUNK_SIZE) as usize-1);
-        y = y.clamp(0, (SIM_HEIGHT/CHUNK_SIZE) as usize-1);
+        let (y, x) = match self.hovered() {
+            Some(tile) => tile,
+            None => return,
+        };
+        match self.tool {
+            Tool::Pencil => {
+                // Mouse samples can be several tiles apart, so connect them to keep strokes gap-free
+                for (y, x) in tools::line(self.last_placed.unwrap_or((y, x)), (y, x)) {
+                    self.place(x, y, self.chunk_type);
+                }
+                self.last_placed = Some((y, x));
+            }
+            Tool::Rect | Tool::RectErase | Tool::Line => {
+                if self.drag_start.is_none() {
+                    self.drag_start = Some((y, x));
+                }
+            }
+            Tool::Fill => {}
+        }
+    }
+
+    /// Fills the region around `start` on the active layer. On the background the region
+    /// stops at tiles hidden by the foreground unless masking was turned off with M.
+    fn bucket_fill(&mut self, start: (usize, usize)) {
+        let masked = self.chunk_mode == 1 && self.fill_masked;
+        let region = tools::flood_fill(&self.chunks[self.chunk_mode], start, |x, y| {
+            masked && self.check_foreground_chunktype(x, y)
+        });
+        for (y, x) in region {
+            self.modified |= self.chunks[self.chunk_mode][y][x] != self.chunk_type;
+            self.chunks[self.chunk_mode][y][x] = self.chunk_type;
+        }
+    }
+
+    /// Sets a tile on the active layer, background tiles hidden behind the foreground are left alone
+    fn place(&mut self, x: usize, y: usize, chunk: ChunkType) {
+        if x >= self.width() || y >= self.height() {
+            return;
+        }
         if self.chunk_mode == 1 && self.check_foreground_chunktype(x, y) {
             return;
         }
-        self.chunks[self.chunk_mode][y][x] = self.chunk_type;
+        self.modified |= self.chunks[self.chunk_mode][y][x] != chunk;
+        self.chunks[self.chunk_mode][y][x] = chunk;
     }
 
-    fn set_spawn(&mut self, input: WinitInputHelper) {
-        if let Some((x, y)) = input.mouse() {
-            self.spawn_chunk = (y as usize/CHUNK_SIZE as usize * 16, x as usize/CHUNK_SIZE as usize * 16);
+    fn set_spawn(&mut self) {
+        if let Some(spawn) = self.hovered() {
+            self.edit(|w| w.spawn_chunk = spawn);
         }
     }
 
     fn set_offset(&mut self, input: WinitInputHelper) {
+        if input.held_control() {
+            return;
+        }
+        // A step is a tile on screen, so scrolling feels the same at every zoom level
+        let step = (CHUNK_SIZE as usize * 100 / ZOOM_LEVELS[self.zoom]) as isize;
         let mut offset = (self.offset.0 as isize, self.offset.1 as isize);
-        if input.key_held(VirtualKeyCode::Right) && offset.1 < (SIM_WIDTH - CHUNK_SIZE) as isize {
-            offset.1 += CHUNK_SIZE as isize;
+        if input.key_held(VirtualKeyCode::Right) {
+            offset.1 += step;
         } else if input.key_held(VirtualKeyCode::Left) {
-            offset.1 -= CHUNK_SIZE as isize;
+            offset.1 -= step;
         } else if input.key_held(VirtualKeyCode::Up) {
-            offset.0 -= CHUNK_SIZE as isize;
+            offset.0 -= step;
         } else if input.key_held(VirtualKeyCode::Down) {
-            offset.0 += CHUNK_SIZE as isize;
+            offset.0 += step;
+        }
+        self.offset = (offset.0.max(0) as usize, offset.1.max(0) as usize);
+        self.clamp_offset();
+    }
+
+    fn clamp_offset(&mut self) {
+        let zoom = ZOOM_LEVELS[self.zoom];
+        let max_y = (self.height() * CHUNK_SIZE as usize).saturating_sub(self.view_height * 100 / zoom);
+        let max_x = (self.width()  * CHUNK_SIZE as usize).saturating_sub(self.view_width  * 100 / zoom);
+        self.offset = (self.offset.0.min(max_y), self.offset.1.min(max_x));
+    }
+
+    fn resize_view(&mut self, width: u32, height: u32) {
+        self.view_width = width as usize;
+        self.view_height = height as usize;
+        self.clamp_offset();
+    }
+
+    /// The mouse wheel and - and = zoom out and in, keeping the point under the cursor in place
+    fn set_zoom(&mut self, input: WinitInputHelper) {
+        if input.held_control() {
+            return;
+        }
+        let scroll = input.scroll_diff();
+        let zoom = if scroll > 0.0 || input.key_pressed(VirtualKeyCode::Equals) || input.key_pressed(VirtualKeyCode::NumpadAdd) {
+            (self.zoom + 1).min(ZOOM_LEVELS.len() - 1)
+        } else if scroll < 0.0 || input.key_pressed(VirtualKeyCode::Minus) || input.key_pressed(VirtualKeyCode::NumpadSubtract) {
+            self.zoom.saturating_sub(1)
+        } else {
+            return;
+        };
+        if zoom == self.zoom {
+            return;
         }
-        offset.0 = offset.0.clamp(0, (SIM_HEIGHT - HEIGHT) as isize);
-        offset.1 = offset.1.clamp(0, (SIM_WIDTH  - WIDTH)  as isize);
-        self.offset = (offset.0 as usize, offset.1 as usize);
+
+        let (y, x) = self.to_world(self.cursor.0, self.cursor.1);
+        self.zoom = zoom;
+        let zoom = ZOOM_LEVELS[self.zoom];
+        self.offset = (y.saturating_sub(self.cursor.0 * 100 / zoom), x.saturating_sub(self.cursor.1 * 100 / zoom));
+        self.clamp_offset();
+        self.status = Some((format!("Zoom {}%", zoom), Instant::now()));
     }
 
+    /// Ctrl+Arrow grows the level towards that edge, Ctrl+Shift+Arrow shrinks it from that edge
+    /// and Ctrl+C crops it to the tiles in use
+    fn set_size(&mut self, input: WinitInputHelper) {
+        if !input.held_control() {
+            return;
+        }
+        let step = if input.held_shift() { -1 } else { 1 };
+        if input.key_pressed(VirtualKeyCode::Left) {
+            self.edit(|w| w.resize(step, 0, 0, 0));
+        } else if input.key_pressed(VirtualKeyCode::Up) {
+            self.edit(|w| w.resize(0, step, 0, 0));
+        } else if input.key_pressed(VirtualKeyCode::Right) {
+            self.edit(|w| w.resize(0, 0, step, 0));
+        } else if input.key_pressed(VirtualKeyCode::Down) {
+            self.edit(|w| w.resize(0, 0, 0, step));
+        } else if input.key_pressed(VirtualKeyCode::C) {
+            self.edit(|w| w.crop());
+        }
+    }
+
+    /// Adds (or with negative values removes) rows and columns on each edge,
+    /// moving the spawn along with the tiles
+    fn resize(&mut self, left: isize, top: isize, right: isize, bottom: isize) {
+        let width  = self.width()  as isize + left + right;
+        let height = self.height() as isize + top + bottom;
+        if width < 1 || height < 1 {
+            return;
+        }
+        self.chunks = [
+            self.chunks[0].resized(left, top, right, bottom),
+            self.chunks[1].resized(left, top, right, bottom),
+        ];
+
+        let spawn_y = (self.spawn_chunk.0 as isize + top).clamp(0, height - 1);
+        let spawn_x = (self.spawn_chunk.1 as isize + left).clamp(0, width  - 1);
+        self.spawn_chunk = (spawn_y as usize, spawn_x as usize);
+        self.drag_start = None;
+        self.last_placed = None;
+        self.clamp_offset();
+    }
+
+    /// Shrinks the level to the smallest rectangle holding every tile and the spawn
+    fn crop(&mut self) {
+        let spawn = (self.spawn_chunk.1, self.spawn_chunk.0, 1, 1);
+        let (x, y, w, h) = [self.chunks[0].used_bounds(), self.chunks[1].used_bounds()]
+            .into_iter()
+            .flatten()
+            .fold(spawn, |(x0, y0, w0, h0), (x1, y1, w1, h1)| {
+                let (x, y) = (x0.min(x1), y0.min(y1));
+                (x, y, (x0 + w0).max(x1 + w1) - x, (y0 + h0).max(y1 + h1) - y)
+            });
+        let right  = (x + w) as isize - self.width()  as isize;
+        let bottom = (y + h) as isize - self.height() as isize;
+        self.resize(-(x as isize), -(y as isize), right, bottom);
+    }
+
+    /// Picks the tile bound to a pressed hotkey, [ and ] step through all tiles
     fn set_chunk_type(&mut self, input: WinitInputHelper) {
-        if input.key_pressed(VirtualKeyCode::Key0) {
-            self.chunk_type = ChunkType::Air;
-        } else if input.key_pressed(VirtualKeyCode::Key1) {
-            self.chunk_type = ChunkType::Dirt;
-        } else if input.key_pressed(VirtualKeyCode::Key2) {
-            self.chunk_type = ChunkType::Grass;
-        } else if input.key_pressed(VirtualKeyCode::Key3) {
-            self.chunk_type = ChunkType::CheckPoint;
-        } else if input.key_pressed(VirtualKeyCode::Key4) {
-            self.chunk_type = ChunkType::Spikes;
-        } else if input.key_pressed(VirtualKeyCode::Key5) {   
-            self.chunk_type = ChunkType::TallGrass;
-        } else if input.key_pressed(VirtualKeyCode::Key6) {   
-            self.chunk_type = ChunkType::Stone;
-        } else if input.key_pressed(VirtualKeyCode::Key7) {   
-            self.chunk_type = ChunkType::Planks;
-        } else if input.key_pressed(VirtualKeyCode::Key8) {   
-            self.chunk_type = ChunkType::CrackedStone;
-        } 
+        if let Some(tile) = self.tiles.iter().find(|t| t.hotkey.is_some_and(|key| input.key_pressed(key))) {
+            self.chunk_type = ChunkType(tile.def.id);
+        } else if input.key_pressed(VirtualKeyCode::LBracket) {
+            self.chunk_type = self.tiles.cycle(self.chunk_type, -1);
+        } else if input.key_pressed(VirtualKeyCode::RBracket) {
+            self.chunk_type = self.tiles.cycle(self.chunk_type, 1);
+        }
+    }
+
+    /// P picks the pencil, R the rectangle fill, E the rectangle erase, B the bucket and L the line tool.
+    /// M toggles whether the bucket respects the foreground mask on the background layer.
+    fn set_tool(&mut self, input: WinitInputHelper) {
+        if input.held_control() {
+            return;
+        }
+        let previous = self.tool;
+        if input.key_pressed(VirtualKeyCode::P) {
+            self.tool = Tool::Pencil;
+        } else if input.key_pressed(VirtualKeyCode::R) {
+            self.tool = Tool::Rect;
+        } else if input.key_pressed(VirtualKeyCode::E) {
+            self.tool = Tool::RectErase;
+        } else if input.key_pressed(VirtualKeyCode::B) {
+            self.tool = Tool::Fill;
+        } else if input.key_pressed(VirtualKeyCode::L) {
+            self.tool = Tool::Line;
+        } else if input.key_pressed(VirtualKeyCode::M) {
+            self.fill_masked = !self.fill_masked;
+        }
+        // A half dragged shape belongs to the tool it was started with
+        if self.tool != previous {
+            self.drag_start = None;
+        }
+    }
+
+    /// Opens the block editor on a copy of the selected tile's art
+    fn open_block_editor(&mut self) {
+        let base = if self.chunk_type == ChunkType::AIR { [[[0; 4]; 32]; 32] } else { *self.tiles.image(self.chunk_type) };
+        self.block_editor = Some(BlockEditor::new(&base));
+    }
+
+    /// Saving adds the block to the tile set and selects it, so it can be placed right away
+    fn edit_block(&mut self, input: WinitInputHelper, mouse: Option<(f32, f32)>) {
+        let editor = match &mut self.block_editor {
+            Some(editor) => editor,
+            None => return,
+        };
+        match editor.update(input, mouse) {
+            BlockAction::None => {}
+            BlockAction::Close => self.block_editor = None,
+            BlockAction::Save => {
+                let name = editor.name.trim().to_string();
+                if name.is_empty() {
+                    editor.message = Some("Give the block a name first".to_string());
+                    return;
+                }
+                if self.tiles.name_taken(&name) {
+                    editor.message = Some(format!("There already is a block named {}", name));
+                    return;
+                }
+                let def = TileDef {
+                    id: 0,
+                    name,
+                    image: None,
+                    atlas_index: None,
+                    hotkey: None,
+                    solid: editor.solid,
+                    hazard: editor.hazard,
+                    checkpoint: editor.checkpoint,
+                };
+                let path = self.tiles.source().map_or_else(|| self.paths.tiles(), Path::to_path_buf);
+                match self.tiles.add(def, editor.canvas, &path) {
+                    Ok(chunk) => {
+                        self.chunk_type = chunk;
+                        self.block_editor = None;
+                    }
+                    Err(e) => {
+                        error!("failed to save block: {}", e);
+                        editor.message = Some("Saving failed, see the log".to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether a panel covering the level has the keyboard
+    fn in_panel(&self) -> bool {
+        self.block_editor.is_some() || self.meta_panel.is_some() || self.prompt.is_some()
+    }
+
+    /// Opens the level metadata panel, everything changed until it closes is a single undo step
+    fn open_meta_panel(&mut self) {
+        let before = self.snapshot();
+        self.history.begin_stroke(before);
+        self.meta_panel = Some(MetaPanel::new());
+    }
+
+    fn edit_meta(&mut self, input: WinitInputHelper, mouse: Option<(f32, f32)>) {
+        let before = self.meta.clone();
+        let close = match &mut self.meta_panel {
+            Some(panel) => panel.update(&mut self.meta, input, mouse),
+            None => return,
+        };
+        self.modified |= self.meta != before;
+        if close {
+            self.meta_panel = None;
+            let after = self.snapshot();
+            self.history.end_stroke(&after);
+        }
     }
 
     fn set_chunk_mode(&mut self) {
-        self.chunk_mode = match self.chunk_mode {
+        self.drag_start = None;
+        self.last_placed = None;
+        self.edit(|w| w.chunk_mode = match w.chunk_mode {
             0 => 1,
             1 => 0,
             _ => 0,
-        }
+        });
     }
 
     fn draw(&self, frame: &mut [u8]) {
-        let chunk_choice = match self.chunk_type {
-            ChunkType::Air          => [[*WHITE; 32]; 32],
-            ChunkType::Dirt         => DIRT,
-            ChunkType::Grass        => GRASS,
-            ChunkType::CheckPoint   => CHECKPOINT,
-            ChunkType::Spikes       => SPIKES,
-            ChunkType::TallGrass    => TALL_GRASS,
-            ChunkType::Stone        => STONE,
-            ChunkType::Planks       => PLANKS,
-            ChunkType::CrackedStone => CRACKED_STONE
+        if let Some(editor) = &self.block_editor {
+            editor.draw(frame, self.view_width);
+            return;
+        }
+
+        let choice = if self.tool == Tool::RectErase { ChunkType::AIR } else { self.chunk_type };
+        let chunk_choice = self.tiles.image(choice);
+        let hovered = self.hovered();
+        let preview = self.drag_start.zip(hovered).map(|(start, end)| tools::rect(start, end));
+        let line_preview: Option<HashSet<(usize, usize)>> = match (self.drag_start, hovered) {
+            (Some(start), Some(end)) if self.tool == Tool::Line => Some(tools::line(start, end).into_iter().collect()),
+            _ => None,
         };
 
-        for (chk, pix) in (0..WIDTH*HEIGHT).zip(frame.chunks_exact_mut(4)) {
-            let mut x = (chk % WIDTH) as usize;
-            let mut y = (chk / WIDTH) as usize;
-            x += self.offset.1;
-            y += self.offset.0;
+        for (chk, pix) in frame.chunks_exact_mut(4).enumerate() {
+            let (y, x) = self.to_world(chk / self.view_width, chk % self.view_width);
+
+            if x/CHUNK_SIZE as usize >= self.width() || y/CHUNK_SIZE as usize >= self.height() {
+                pix.copy_from_slice(BLACK);
+                continue;
+            }
 
             let chunk = self.chunks[self.chunk_mode][y/CHUNK_SIZE as usize][x/CHUNK_SIZE as usize];
 
-            let mut rgba = match chunk {
-                ChunkType::Air          => *WHITE,
-                ChunkType::Dirt         => image_pixels(DIRT, x, y),
-                ChunkType::Grass        => image_pixels(GRASS, x, y),
-                ChunkType::CheckPoint   => image_pixels(CHECKPOINT, x, y),
-                ChunkType::Spikes       => image_pixels(SPIKES, x, y),
-                ChunkType::TallGrass    => image_pixels(TALL_GRASS, x, y),
-                ChunkType::Stone        => image_pixels(STONE, x, y),
-                ChunkType::Planks       => image_pixels(PLANKS, x, y),
-                ChunkType::CrackedStone => image_pixels(CRACKED_STONE, x, y)
+            let mut rgba = image_pixels(self.tiles.image(chunk), x, y);
+
+            let previewed = match (&line_preview, preview) {
+                (Some(line), _) => line.contains(&(y/CHUNK_SIZE as usize, x/CHUNK_SIZE as usize)),
+                (None, Some((y0, x0, y1, x1))) => (y0..=y1).contains(&(y/CHUNK_SIZE as usize)) && (x0..=x1).contains(&(x/CHUNK_SIZE as usize)),
+                (None, None) => hovered == Some((y/CHUNK_SIZE as usize, x/CHUNK_SIZE as usize)),
             };
-            
-            if self.tmp_chunk == ((y - self.offset.0)/CHUNK_SIZE as usize, (x - self.offset.1)/CHUNK_SIZE as usize) {
+            if previewed {
                 rgba = image_pixels(chunk_choice, x, y);
                 rgba[3] = 0x64;
             }
 
-            if self.spawn_chunk == (y/CHUNK_SIZE as usize*16, x/CHUNK_SIZE as usize*16) && image_pixels(SPAWN, x, y)[3] != 0 {
-                rgba = image_pixels(SPAWN, x, y);
+            if self.spawn_chunk == (y/CHUNK_SIZE as usize, x/CHUNK_SIZE as usize) {
+                render::overlay(&mut rgba, image_pixels(images::spawn(), x, y));
             }
 
-            if self.chunk_mode == 1 && self.check_foreground_chunktype(x/CHUNK_SIZE as usize, y/CHUNK_SIZE as usize) && image_pixels(NOT_SHOWN, x, y)[3] != 0 {
-                rgba = image_pixels(NOT_SHOWN, x, y);
+            if self.chunk_mode == 1 && self.check_foreground_chunktype(x/CHUNK_SIZE as usize, y/CHUNK_SIZE as usize) {
+                render::overlay(&mut rgba, image_pixels(images::not_shown(), x, y));
             }
             
             pix.copy_from_slice(&rgba);
         }
+
+        if let Some(panel) = &self.meta_panel {
+            panel.draw(frame, self.view_width, &self.meta);
+        }
+
+        if let Some(prompt) = &self.prompt {
+            prompt.draw(frame, self.view_width, self.view_height);
+        }
+
+        if let Some((message, _)) = self.status.as_ref().filter(|(_, shown)| shown.elapsed() < STATUS_TIME) {
+            let bar = ui::Rect::new(0, self.view_height - 16, self.view_width, 16);
+            ui::fill_rect(frame, self.view_width, bar, BLACK);
+            ui::draw_text(frame, self.view_width, 4, bar.y + 4, message, WHITE, 1);
+        }
     }
 
-    fn save(&self) {
-        if File::open(EXPORT).is_ok() {
-            fs::remove_file(EXPORT).unwrap();
+    /// Writes the level to the three files the game reads, the level in memory is left as is if that fails
+    fn export(&self) -> Result<(), SaveError> {
+        let format = self.format;
+        if let Some(id) = self.chunks.iter().flat_map(|l| l.rows().flatten()).map(|c| c.0).find(|&id| id > format.max_id()) {
+            return Err(SaveError::IdTooLarge(id));
         }
-        let mut file = File::create(EXPORT).unwrap();
-        for h in self.chunks[0].iter() {
-            for w in h.iter() {
-                write!(file, "{}", *w as u8).unwrap();
+        create_parent(&self.paths.map)?;
+        // The game needs all three to match, so none is replaced unless every one was written
+        let mut batch = save::Batch::new(self.backups);
+        batch.write(&self.paths.map, |file| self.write_layer(file, 0, format))?;
+        batch.write(&self.paths.bg, |file| self.write_layer(file, 1, format))?;
+        batch.write(&self.paths.json, |file| {
+            let json = MapJson {
+                x: self.spawn_chunk.1 * GAME_TILE,
+                y: self.spawn_chunk.0 * GAME_TILE,
+                meta: self.meta.clone(),
+            };
+            save::write_json(file, &json)
+        })?;
+        batch.commit()
+    }
+
+    /// Renders the level to a PNG
+    fn export_image(&self, path: &Path, options: ImageOptions) -> Result<(), SaveError> {
+        create_parent(path)?;
+        render::render(&self.chunks, &self.tiles, self.spawn_chunk, options)
+            .save(path)
+            .map_err(|e| SaveError::Image(path.to_path_buf(), e))
+    }
+
+    /// Renders the level next to its map with the options given on the command line
+    fn export_image_with_status(&mut self) {
+        let path = self.paths.map.with_extension(PNG_EXT);
+        self.export_file_with_status(&path, "image", |w, path| w.export_image(path, w.image_options));
+    }
+
+    /// Exports the level to `<name>.tmx` next to its map for editing in Tiled
+    fn export_tiled_with_status(&mut self) {
+        let path = self.paths.map.with_extension(TMX_EXT);
+        self.export_file_with_status(&path, "Tiled map", |w, path| tiled::export(&w.snapshot(), &w.tiles, path));
+    }
+
+    /// Exports the level to `<name>.ldtk` next to its map for editing in LDtk
+    fn export_ldtk_with_status(&mut self) {
+        let path = self.paths.map.with_extension(LDTK_EXT);
+        self.export_file_with_status(&path, "LDtk project", |w, path| ldtk::export(&w.snapshot(), &w.tiles, path));
+    }
+
+    /// Runs `export` to write the level to `path` and reports how it went in the status bar,
+    /// `label` names what is written in the log
+    fn export_file_with_status(&mut self, path: &Path, label: &str, export: impl FnOnce(&Self, &Path) -> Result<(), SaveError>) {
+        let message = match create_parent(path).and_then(|()| export(self, path)) {
+            Ok(()) => format!("Exported the level to {}", path.display()),
+            Err(e) => {
+                error!("failed to export {}: {}", label, e);
+                format!("Exporting failed: {}", e)
             }
-            writeln!(file).unwrap();
-        }
-        
-        if File::open(EXPORT_BG).is_ok() {
-            fs::remove_file(EXPORT_BG).unwrap();
+        };
+        self.status = Some((message, Instant::now()));
+    }
+
+    /// Replaces the level with the Tiled map Ctrl+T exported, as a single undoable edit
+    fn import_tiled_with_status(&mut self) {
+        let path = self.paths.map.with_extension(TMX_EXT);
+        let message = match tiled::import(&path, &self.tiles) {
+            Ok(level) => {
+                self.edit(|w| w.restore(level));
+                format!("Imported {}", path.display())
+            }
+            Err(e) => {
+                error!("failed to import Tiled map: {}", e);
+                format!("Importing failed: {}", e)
+            }
+        };
+        self.status = Some((message, Instant::now()));
+    }
+
+    fn save_project(&self) -> Result<(), SaveError> {
+        self.write_project(&self.paths.project, self.backups)
+    }
+
+    fn write_project(&self, path: &Path, backups: usize) -> Result<(), SaveError> {
+        create_parent(path)?;
+        let project = Project::new(&self.snapshot(), &self.tiles);
+        save::write_file(path, backups, |file| save::write_json(file, &project))
+    }
+
+    /// Writes a layer in the given format, on the background tiles hidden by the foreground are written as air
+    fn write_layer(&self, file: &mut impl Write, mode: usize, format: MapFormat) -> io::Result<()> {
+        if format != MapFormat::Legacy {
+            writeln!(file, "{} {}", format::HEADER, format::VERSION)?;
         }
-        let mut file = File::create(EXPORT_BG).unwrap();
-        for (y, h) in self.chunks[1].iter().enumerate() {
+        for (y, h) in self.chunks[mode].rows().enumerate() {
             for (x, w) in h.iter().enumerate() {
-                if self.check_foreground_chunktype(x, y) {
-                    write!(file, "0").unwrap();
+                if x > 0 {
+                    write!(file, "{}", format.separator())?;
+                }
+                if mode == 1 && self.check_foreground_chunktype(x, y) {
+                    write!(file, "0")?;
                 } else {
-                    write!(file, "{}", *w as u8).unwrap();
+                    write!(file, "{}", w.0)?;
                 }
             }
-            writeln!(file).unwrap();
+            writeln!(file)?;
         }
+        Ok(())
+    }
+
+    /// Saves the project and leaves a message in the window saying how it went, returns whether it worked
+    fn save_with_status(&mut self) -> bool {
+        let result = self.save_project();
+        let path = self.paths.project.clone();
+        self.report_save(result, &path)
+    }
+
+    /// Exports to the game's files and leaves a message in the window saying how it went
+    fn export_with_status(&mut self) -> bool {
+        let result = self.export();
+        let path = self.paths.map.clone();
+        self.report_save(result, &path)
+    }
+
+    fn report_save(&mut self, result: Result<(), SaveError>, path: &Path) -> bool {
+        let (message, saved) = match result {
+            Ok(()) => {
+                self.modified = false;
+                self.autosaved = None;
+                self.paths.remove_recovery();
+                (format!("Saved to {}", path.display()), true)
+            }
+            Err(e) => {
+                error!("failed to save level: {}", e);
+                (format!("Saving failed: {}", e), false)
+            }
+        };
+        self.status = Some((message, Instant::now()));
+        saved
+    }
 
-        if File::open(EX_JSON).is_ok() {
-            fs::remove_file(EX_JSON).unwrap();
+    /// Writes unsaved changes to the recovery files every `AUTOSAVE_TIME`
+    fn autosave(&mut self) {
+        if self.last_autosave.elapsed() < AUTOSAVE_TIME {
+            return;
+        }
+        self.last_autosave = Instant::now();
+        let snapshot = self.snapshot();
+        if !self.modified || self.autosaved.as_ref().is_some_and(|a| snapshot.same_level(a)) {
+            return;
+        }
+        match self.write_project(&self.paths.recovery(), 0) {
+            Ok(()) => self.autosaved = Some(snapshot),
+            Err(e) => {
+                error!("autosave failed: {}", e);
+                self.status = Some((format!("Autosave failed: {}", e), Instant::now()));
+            }
+        }
+    }
+
+    /// Asks whether to restore an autosave left behind by a session that was not saved
+    fn check_recovery(&mut self) {
+        if self.paths.recovery_newer() {
+            self.prompt = Some(Prompt::Restore);
+        }
+    }
+
+    /// Whether the editor can close now, otherwise asks about unsaved changes first.
+    /// Closing the window while already asked closes it anyway.
+    fn quit_requested(&mut self, closed: bool) -> bool {
+        if !self.modified || (closed && self.prompt == Some(Prompt::Quit)) {
+            return true;
+        }
+        self.prompt = Some(Prompt::Quit);
+        false
+    }
+
+    /// Handles the answer to the open prompt, returns whether the editor should close
+    fn answer_prompt(&mut self, input: &WinitInputHelper) -> bool {
+        let (prompt, answer) = match (self.prompt, Prompt::answer(input)) {
+            (Some(prompt), Some(answer)) => (prompt, answer),
+            _ => return false,
+        };
+        self.prompt = None;
+        match (prompt, answer) {
+            (Prompt::Restore, Answer::Yes) => match Project::load(&self.paths.recovery()).and_then(|p| p.into_level(&self.paths.recovery(), &mut self.tiles)) {
+                Ok(level) => {
+                    self.edit(|w| w.restore(level));
+                    self.status = Some(("Restored the autosave".to_string(), Instant::now()));
+                }
+                Err(e) => {
+                    error!("failed to restore autosave: {}", e);
+                    self.status = Some((format!("Restoring failed: {}", e), Instant::now()));
+                }
+            },
+            (Prompt::Restore, Answer::No) => self.paths.remove_recovery(),
+            // Dismissing the prompt only closes it, the autosave stays
+            (Prompt::Restore, Answer::Cancel) => {}
+            (Prompt::Quit, Answer::Yes) => return self.save_with_status(),
+            (Prompt::Quit, Answer::No) => {
+                self.paths.remove_recovery();
+                return true;
+            }
+            (Prompt::Quit, Answer::Cancel) => {}
         }
-        let mut file = File::create(EX_JSON).unwrap();
-        writeln!(file, "{{").unwrap();
-        writeln!(file, "\t\"x\": {},", self.spawn_chunk.1).unwrap();
-        writeln!(file, "\t\"y\": {},", self.spawn_chunk.0).unwrap();
-        writeln!(file, "\t\"depth\": 750").unwrap();
-        writeln!(file, "}}").unwrap();
+        false
+    }
+
+    /// Opens whichever of the project and the exported files was written last, or a Tiled map
+    fn open(paths: &MapPaths, tiles: TileSet) -> Result<Self, LoadError> {
+        if paths.map.extension().is_some_and(|ext| ext == TMX_EXT) {
+            let level = tiled::import(&paths.map, &tiles)?;
+            let mut world = World::new(paths.clone(), tiles, level.chunks[0].width(), level.chunks[0].height())?;
+            world.restore(level);
+            world.modified = true;
+            Ok(world)
+        } else if paths.project_newer() {
+            Self::load_project(paths, tiles)
+        } else {
+            Self::load(paths, tiles)
+        }
+    }
+
+    fn load_project(paths: &MapPaths, mut tiles: TileSet) -> Result<Self, LoadError> {
+        let level = Project::load(&paths.project)?.into_level(&paths.project, &mut tiles)?;
+        let mut world = World::new(paths.clone(), tiles, level.chunks[0].width(), level.chunks[0].height())?;
+        world.restore(level);
+        Ok(world)
+    }
+
+    /// Builds a new level from colour-key images, using the palette file or the built-in colours
+    fn import(paths: MapPaths, tiles: TileSet, fg: &Path, bg: Option<&Path>, palette: Option<&Path>) -> Result<Self, LoadError> {
+        let palette = match palette {
+            Some(path) => Palette::load(path, &tiles)?,
+            None => Palette::builtin(&tiles)?,
+        };
+        let level = palette.import(fg, bg)?;
+        let mut world = World::new(paths, tiles, level.chunks[0].width(), level.chunks[0].height())?;
+        world.restore(level);
+        world.modified = true;
+        Ok(world)
+    }
+
+    fn load(paths: &MapPaths, tiles: TileSet) -> Result<Self, LoadError> {
+        let (level, format) = Self::read(paths, &tiles)?;
+        let mut world = World::new(paths.clone(), tiles, level.chunks[0].width(), level.chunks[0].height())?;
+        world.restore(level);
+        world.format = format;
+        Ok(world)
+    }
+
+    /// Reads a level from its three files, along with the format its foreground was written in
+    fn read(paths: &MapPaths, tiles: &TileSet) -> Result<(Snapshot, MapFormat), LoadError> {
+        let (fg, format) = Self::load_layer(&paths.map, tiles)?;
+        let (bg, _) = Self::load_layer(&paths.bg, tiles)?;
+        if bg.width() != fg.width() {
+            return Err(LoadError::WrongWidth { path: paths.bg.clone(), line: 1, expected: fg.width(), found: bg.width() });
+        }
+        if bg.height() != fg.height() {
+            return Err(LoadError::WrongHeight { path: paths.bg.clone(), expected: fg.height(), found: bg.height() });
+        }
+
+        let json = fs::read_to_string(&paths.json).map_err(|e| LoadError::Io(paths.json.clone(), e))?;
+        let json: MapJson = serde_json::from_str(&json).map_err(|e| LoadError::Json(paths.json.clone(), e))?;
+        let spawn_chunk = (
+            (json.y / GAME_TILE).min(fg.height() - 1),
+            (json.x / GAME_TILE).min(fg.width() - 1),
+        );
+
+        let level = Snapshot {
+            chunks: [fg, bg],
+            spawn_chunk,
+            chunk_mode: 0,
+            meta: json.meta,
+        };
+        Ok((level, format))
+    }
+
+    /// Reads a map in either format, sized after its first row and number of rows
+    fn load_layer(path: &Path, tiles: &TileSet) -> Result<(Layer, MapFormat), LoadError> {
+        let text = fs::read_to_string(path).map_err(|e| LoadError::Io(path.to_path_buf(), e))?;
+        let rows: Vec<&str> = text.lines().map(|l| l.trim_end_matches('\r')).collect();
+        let (format, skipped) = match rows.first().and_then(|r| r.strip_prefix(format::HEADER)) {
+            Some(version) if version.trim() == format::VERSION => (MapFormat::V2, 1),
+            Some(version) => return Err(LoadError::UnsupportedVersion { path: path.to_path_buf(), version: version.trim().to_string() }),
+            None => (MapFormat::Legacy, 0),
+        };
+        let rows: Vec<Vec<&str>> = match rows.iter().rposition(|r| !r.is_empty()) {
+            Some(last) if last >= skipped => rows[skipped..=last].iter().map(|r| format.split_row(r)).collect(),
+            _ => Vec::new(),
+        };
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if width == 0 || height == 0 {
+            return Err(LoadError::InvalidSize { path: path.to_path_buf(), width, height });
+        }
+
+        let mut layer = Layer::new(width, height);
+
+        for (y, row) in rows.iter().enumerate() {
+            let line = y + 1 + skipped;
+            if row.len() != width {
+                return Err(LoadError::WrongWidth { path: path.to_path_buf(), line, expected: width, found: row.len() });
+            }
+            for (x, cell) in row.iter().enumerate() {
+                let column = x + 1;
+                let id: u16 = cell.parse().map_err(|_| LoadError::MalformedRow { path: path.to_path_buf(), line, column, found: cell.to_string() })?;
+                if !tiles.contains(ChunkType(id)) {
+                    return Err(LoadError::UnknownTile { path: path.to_path_buf(), line, column, id });
+                }
+                layer[y][x] = ChunkType(id);
+            }
+        }
+
+        Ok((layer, format))
     }
 
     fn check_foreground_chunktype(&self, x: usize, y: usize) -> bool {
-        self.chunks[0][y][x] != ChunkType::Air &&
-        self.chunks[0][y][x] != ChunkType::CheckPoint &&
-        self.chunks[0][y][x] != ChunkType::Spikes &&
-        self.chunks[0][y][x] != ChunkType::TallGrass
+        self.tiles.is_solid(self.chunks[0][y][x])
     }
 }
 
-fn image_pixels(image: [[[u8; 4]; 32]; 32], x: usize, y: usize) -> [u8; 4] {
-    image[y%CHUNK_SIZE as usize][x%CHUNK_SIZE as usize]
-}
\ No newline at end of file
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The frame for the window's current size. Each frame pixel covers a whole number of physical
+/// pixels, so the art stays sharp and the editor keeps its size on HiDPI screens.
+fn buffer_size(window: &Window) -> (u32, u32) {
+    let size = window.inner_size();
+    let scale = window.scale_factor().floor().max(1.0) as u32;
+    (size.width / scale, size.height / scale)
+}
+
+fn create_parent(path: &Path) -> Result<(), SaveError> {
+    match path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        Some(dir) => fs::create_dir_all(dir).map_err(|e| SaveError::Io(dir.to_path_buf(), e)),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use winit::event::VirtualKeyCode;
+use winit_input_helper::{TextChar, WinitInputHelper};
+use crate::ui::{self, Rect};
+use crate::{BLACK, WHITE};
+
+const PANEL: Rect = Rect::new(16, 16, 480, 296);
+const ROW:   usize = 40;
+const TEXT_LENGTH: usize = 32;
+
+const GREY:  &[u8; 4] = &[0x80, 0x80, 0x80, 0xff];
+const LIGHT: &[u8; 4] = &[0xcc, 0xcc, 0xcc, 0xff];
+const DARK:  &[u8; 4] = &[0x30, 0x30, 0x30, 0xff];
+
+fn default_depth() -> i32 {
+    750
+}
+
+/// Everything about a level besides its tiles, stored in map.json next to the spawn
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelMeta {
+    #[serde(default = "default_depth")]
+    pub depth: i32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub music: String,
+    /// In seconds, 0 means there is no limit
+    #[serde(default)]
+    pub time_limit: u32,
+    #[serde(default)]
+    pub next_level: String,
+}
+
+impl Default for LevelMeta {
+    fn default() -> Self {
+        LevelMeta {
+            depth: default_depth(),
+            name: String::new(),
+            author: String::new(),
+            music: String::new(),
+            time_limit: 0,
+            next_level: String::new(),
+        }
+    }
+}
+
+const FIELDS: [&str; 6] = ["Level name", "Author", "Music track", "Next level", "Depth", "Time limit (s)"];
+
+/// A panel over the level for editing its `LevelMeta`
+pub struct MetaPanel {
+    field: usize,
+}
+
+impl MetaPanel {
+    pub fn new() -> Self {
+        MetaPanel { field: 0 }
+    }
+
+    /// Up and Down pick a field, typing edits it and Tab, Return or Escape close the panel.
+    /// Returns whether the panel should close.
+    pub fn update(&mut self, meta: &mut LevelMeta, input: WinitInputHelper, mouse: Option<(f32, f32)>) -> bool {
+        if input.key_pressed(VirtualKeyCode::Tab) ||
+           input.key_pressed(VirtualKeyCode::Return) ||
+           input.key_pressed(VirtualKeyCode::Escape) {
+            return true;
+        }
+        if input.key_pressed(VirtualKeyCode::Up) {
+            self.field = (self.field + FIELDS.len() - 1) % FIELDS.len();
+        } else if input.key_pressed(VirtualKeyCode::Down) {
+            self.field = (self.field + 1) % FIELDS.len();
+        }
+        if input.mouse_pressed(0) {
+            if let Some(field) = mouse.and_then(|mouse| (0..FIELDS.len()).find(|&i| field_rect(i).contains(mouse))) {
+                self.field = field;
+            }
+        }
+
+        for c in input.text() {
+            match self.field {
+                0 => edit_text(&mut meta.name, c),
+                1 => edit_text(&mut meta.author, c),
+                2 => edit_text(&mut meta.music, c),
+                3 => edit_text(&mut meta.next_level, c),
+                4 => meta.depth = edit_number(meta.depth, c),
+                _ => meta.time_limit = edit_number(meta.time_limit, c),
+            }
+        }
+        false
+    }
+
+    pub fn draw(&self, frame: &mut [u8], width: usize, meta: &LevelMeta) {
+        ui::fill_rect(frame, width, PANEL, DARK);
+        ui::stroke_rect(frame, width, PANEL, GREY);
+        ui::draw_text(frame, width, PANEL.x + 8, PANEL.y + 8, "Level  (Up/Down to pick, Tab to close)", LIGHT, 1);
+
+        let values = [
+            meta.name.clone(),
+            meta.author.clone(),
+            meta.music.clone(),
+            meta.next_level.clone(),
+            meta.depth.to_string(),
+            meta.time_limit.to_string(),
+        ];
+        for (i, (label, value)) in FIELDS.iter().zip(values).enumerate() {
+            let rect = field_rect(i);
+            ui::draw_text(frame, width, PANEL.x + 8, rect.y + 8, label, LIGHT, 1);
+            ui::fill_rect(frame, width, rect, if i == self.field { WHITE } else { LIGHT });
+            let cursor = if i == self.field { "_" } else { "" };
+            ui::draw_text(frame, width, rect.x + 4, rect.y + 8, &format!("{}{}", value, cursor), BLACK, 1);
+        }
+    }
+}
+
+fn field_rect(i: usize) -> Rect {
+    Rect::new(PANEL.x + 104, PANEL.y + 32 + i * ROW, PANEL.w - 112, 24)
+}
+
+fn edit_text(text: &mut String, c: TextChar) {
+    match c {
+        TextChar::Char(c) if (c.is_ascii_graphic() || c == ' ') && text.len() < TEXT_LENGTH => text.push(c),
+        TextChar::Back => { text.pop(); }
+        _ => {}
+    }
+}
+
+/// Applies a keystroke to the digits of a number, anything but digits and Backspace is ignored
+fn edit_number<T: ToString + std::str::FromStr + Default>(value: T, c: TextChar) -> T {
+    let mut digits = value.to_string();
+    match c {
+        TextChar::Char(c) if c.is_ascii_digit() => {
+            if digits == "0" {
+                digits.clear();
+            }
+            digits.push(c);
+        }
+        TextChar::Back => { digits.pop(); }
+        _ => return value,
+    }
+    if digits.is_empty() {
+        return T::default();
+    }
+    digits.parse().unwrap_or(value)
+}
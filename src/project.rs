@@ -0,0 +1,175 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::format::MapFormat;
+use crate::history::Snapshot;
+use crate::images::{self, Image};
+use crate::layer::Layer;
+use crate::meta::LevelMeta;
+use crate::tiles::{TileDef, TileSet};
+use crate::{ChunkType, LoadError};
+
+/// Bumped whenever the layout changes in a way older editors cannot read
+const PROJECT_VERSION: u32 = 1;
+
+/// The editor's own file for a level, holding everything needed to open it again in one place
+#[derive(Serialize, Deserialize)]
+pub struct Project {
+    version: u32,
+    width: usize,
+    height: usize,
+    /// Rows of comma separated tile ids
+    foreground: Vec<String>,
+    background: Vec<String>,
+    /// The spawn tile
+    spawn: Spawn,
+    meta: LevelMeta,
+    /// Every tile the layers use, so the level opens the same with another tile set
+    tiles: Vec<ProjectTile>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Spawn {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ProjectTile {
+    #[serde(flatten)]
+    def: TileDef,
+    /// Art of tiles that do not ship with the editor, a row of `rrggbbaa` pixels per string
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pixels: Option<Vec<String>>,
+}
+
+impl Project {
+    pub fn new(level: &Snapshot, tiles: &TileSet) -> Self {
+        let used: BTreeSet<ChunkType> = level.chunks
+            .iter()
+            .flat_map(|layer| layer.rows().flatten().copied())
+            .collect();
+        let tiles = used
+            .into_iter()
+            .filter_map(|chunk| tiles.get(chunk))
+            .map(|tile| {
+                let mut def = tile.def.clone();
+                def.image = None;
+                def.atlas_index = None;
+                let pixels = images::builtin(&def.name).is_none().then(|| encode(&tile.image));
+                ProjectTile { def, pixels }
+            })
+            .collect();
+
+        Project {
+            version: PROJECT_VERSION,
+            width: level.chunks[0].width(),
+            height: level.chunks[0].height(),
+            foreground: write_layer(&level.chunks[0]),
+            background: write_layer(&level.chunks[1]),
+            spawn: Spawn { x: level.spawn_chunk.1, y: level.spawn_chunk.0 },
+            meta: level.meta.clone(),
+            tiles,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, LoadError> {
+        let json = fs::read_to_string(path).map_err(|e| LoadError::Io(path.to_path_buf(), e))?;
+        serde_json::from_str(&json).map_err(|e| LoadError::Json(path.to_path_buf(), e))
+    }
+
+    /// Turns the project back into a level. Its tiles are matched to `tiles` by name,
+    /// the ones missing there are added to it.
+    pub fn into_level(self, path: &Path, tiles: &mut TileSet) -> Result<Snapshot, LoadError> {
+        if self.version > PROJECT_VERSION {
+            return Err(LoadError::UnsupportedVersion { path: path.to_path_buf(), version: self.version.to_string() });
+        }
+        if self.width == 0 || self.height == 0 {
+            return Err(LoadError::InvalidSize { path: path.to_path_buf(), width: self.width, height: self.height });
+        }
+
+        let mut ids = HashMap::new();
+        for tile in self.tiles {
+            let id = tile.def.id;
+            let chunk = match tiles.find(&tile.def.name) {
+                Some(chunk) => chunk,
+                None => {
+                    let image = match &tile.pixels {
+                        Some(pixels) => decode(pixels).ok_or_else(|| LoadError::MalformedPixels { path: path.to_path_buf(), tile: tile.def.name.clone() })?,
+                        None => images::placeholder(),
+                    };
+                    tiles.insert(tile.def, image, path).map_err(LoadError::Tiles)?
+                }
+            };
+            ids.insert(id, chunk);
+        }
+
+        let read = |rows: &[String]| read_layer(path, rows, self.width, self.height, &ids, tiles);
+        let foreground = read(&self.foreground)?;
+        let background = read(&self.background)?;
+        Ok(Snapshot {
+            chunks: [foreground, background],
+            spawn_chunk: (self.spawn.y.min(self.height - 1), self.spawn.x.min(self.width - 1)),
+            chunk_mode: 0,
+            meta: self.meta,
+        })
+    }
+}
+
+fn write_layer(layer: &Layer) -> Vec<String> {
+    layer.rows()
+        .map(|row| row.iter().map(|chunk| chunk.0.to_string()).collect::<Vec<_>>().join(MapFormat::V2.separator()))
+        .collect()
+}
+
+/// Reads rows of ids, translating them through `ids` into the tile set's own
+fn read_layer(path: &Path, rows: &[String], width: usize, height: usize, ids: &HashMap<u16, ChunkType>, tiles: &TileSet) -> Result<Layer, LoadError> {
+    if rows.len() != height {
+        return Err(LoadError::WrongHeight { path: path.to_path_buf(), expected: height, found: rows.len() });
+    }
+    let mut layer = Layer::new(width, height);
+    for (y, row) in rows.iter().enumerate() {
+        let line = y + 1;
+        let cells = MapFormat::V2.split_row(row);
+        if cells.len() != width {
+            return Err(LoadError::WrongWidth { path: path.to_path_buf(), line, expected: width, found: cells.len() });
+        }
+        for (x, cell) in cells.iter().enumerate() {
+            let column = x + 1;
+            let id: u16 = cell.parse().map_err(|_| LoadError::MalformedRow { path: path.to_path_buf(), line, column, found: cell.to_string() })?;
+            let chunk = match ids.get(&id) {
+                Some(chunk) => *chunk,
+                None if tiles.contains(ChunkType(id)) => ChunkType(id),
+                None => return Err(LoadError::UnknownTile { path: path.to_path_buf(), line, column, id }),
+            };
+            layer[y][x] = chunk;
+        }
+    }
+    Ok(layer)
+}
+
+fn encode(image: &Image) -> Vec<String> {
+    image.iter()
+        .map(|row| row.iter().flatten().map(|byte| format!("{:02x}", byte)).collect())
+        .collect()
+}
+
+fn decode(rows: &[String]) -> Option<Image> {
+    let mut image = images::BLANK;
+    if rows.len() != image.len() {
+        return None;
+    }
+    for (row, text) in image.iter_mut().zip(rows) {
+        if text.len() != row.len() * 8 || !text.is_ascii() {
+            return None;
+        }
+        for (x, pixel) in row.iter_mut().enumerate() {
+            for (i, byte) in pixel.iter_mut().enumerate() {
+                let at = x * 8 + i * 2;
+                *byte = u8::from_str_radix(&text[at..at + 2], 16).ok()?;
+            }
+        }
+    }
+    Some(image)
+}
@@ -0,0 +1,59 @@
+use winit::event::VirtualKeyCode;
+use winit_input_helper::WinitInputHelper;
+use crate::ui::{self, Rect};
+use crate::WHITE;
+
+const GREY: &[u8; 4] = &[0x80, 0x80, 0x80, 0xff];
+const DARK: &[u8; 4] = &[0x30, 0x30, 0x30, 0xff];
+
+/// A yes or no question shown over the level
+#[derive(Clone, Copy, PartialEq)]
+pub enum Prompt {
+    /// An autosave newer than the last save was found on startup
+    Restore,
+    /// The editor is being closed with unsaved changes
+    Quit,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Answer {
+    Yes,
+    No,
+    Cancel,
+}
+
+impl Prompt {
+    fn lines(&self) -> [&'static str; 2] {
+        match self {
+            Prompt::Restore => ["Found an autosave newer than the level.", "Restore it? (Y)es / (N)o"],
+            Prompt::Quit => ["The level has unsaved changes.", "Save before quitting? (Y)es / (N)o / (Esc) keep editing"],
+        }
+    }
+
+    /// Y answers yes, N no and Escape cancels
+    pub fn answer(input: &WinitInputHelper) -> Option<Answer> {
+        if input.key_pressed(VirtualKeyCode::Y) {
+            Some(Answer::Yes)
+        } else if input.key_pressed(VirtualKeyCode::N) {
+            Some(Answer::No)
+        } else if input.key_pressed(VirtualKeyCode::Escape) {
+            Some(Answer::Cancel)
+        } else {
+            None
+        }
+    }
+
+    /// Draws the question in a box in the middle of the frame
+    pub fn draw(&self, frame: &mut [u8], width: usize, height: usize) {
+        let lines = self.lines();
+        let text_width = lines.iter().map(|l| ui::text_width(l, 2)).max().unwrap_or(0);
+        let w = text_width + 32;
+        let h = lines.len() * 24 + 24;
+        let rect = Rect::new(width.saturating_sub(w) / 2, height.saturating_sub(h) / 2, w, h);
+        ui::fill_rect(frame, width, rect, DARK);
+        ui::stroke_rect(frame, width, rect, GREY);
+        for (i, line) in lines.iter().enumerate() {
+            ui::draw_text(frame, width, rect.x + 16, rect.y + 16 + i * 24, line, WHITE, 2);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use clap::ValueEnum;
+use crate::images::{self, Bitmap, Image};
+use crate::layer::Layer;
+use crate::tiles::TileSet;
+use crate::{ChunkType, CHUNK_SIZE};
+
+/// Which layers a level image shows
+#[derive(Clone, Copy, PartialEq, Debug, ValueEnum)]
+pub enum ImageLayers {
+    /// The foreground over the background
+    Both,
+    Foreground,
+    Background,
+}
+
+/// How a level is rendered to an image
+#[derive(Clone, Copy)]
+pub struct ImageOptions {
+    pub layers: ImageLayers,
+    /// Output pixels per world pixel
+    pub scale: f32,
+}
+
+/// Covers `rgba` with `top` wherever `top` is not fully transparent, the way the editor stacks its markers
+pub fn overlay(rgba: &mut [u8; 4], top: [u8; 4]) {
+    if top[3] != 0 {
+        *rgba = top;
+    }
+}
+
+pub fn image_pixels(image: &Image, x: usize, y: usize) -> [u8; 4] {
+    image[y % CHUNK_SIZE as usize][x % CHUNK_SIZE as usize]
+}
+
+/// The level's pixel at world pixel `x`, `y`. Air in the foreground lets the background through
+/// and background tiles hidden by solid foreground are marked the way the editor does.
+fn level_pixel(chunks: &[Layer; 2], tiles: &TileSet, spawn: (usize, usize), layers: ImageLayers, x: usize, y: usize) -> [u8; 4] {
+    let tile = (y / CHUNK_SIZE as usize, x / CHUNK_SIZE as usize);
+    let pixel = |layer: &Layer| image_pixels(tiles.image(layer[tile.0][tile.1]), x, y);
+    let mut rgba = match layers {
+        ImageLayers::Foreground => pixel(&chunks[0]),
+        ImageLayers::Background => {
+            // Like the editor, mark tiles the game never shows behind solid foreground
+            let mut rgba = pixel(&chunks[1]);
+            if tiles.is_solid(chunks[0][tile.0][tile.1]) {
+                overlay(&mut rgba, image_pixels(images::not_shown(), x, y));
+            }
+            rgba
+        }
+        ImageLayers::Both => {
+            let mut rgba = pixel(&chunks[1]);
+            if chunks[0][tile.0][tile.1] != ChunkType::AIR {
+                overlay(&mut rgba, pixel(&chunks[0]));
+            }
+            rgba
+        }
+    };
+    if spawn == tile {
+        overlay(&mut rgba, image_pixels(images::spawn(), x, y));
+    }
+    rgba
+}
+
+/// Draws the whole level into a picture, sampling the nearest world pixel when scaled
+pub fn render(chunks: &[Layer; 2], tiles: &TileSet, spawn: (usize, usize), options: ImageOptions) -> Bitmap {
+    let world_width  = chunks[0].width()  * CHUNK_SIZE as usize;
+    let world_height = chunks[0].height() * CHUNK_SIZE as usize;
+    let width  = ((world_width  as f32 * options.scale).round() as usize).max(1);
+    let height = ((world_height as f32 * options.scale).round() as usize).max(1);
+    let mut bitmap = Bitmap::new(width, height);
+    for y in 0..height {
+        let world_y = ((y as f32 / options.scale) as usize).min(world_height - 1);
+        for x in 0..width {
+            let world_x = ((x as f32 / options.scale) as usize).min(world_width - 1);
+            bitmap.set(x, y, level_pixel(chunks, tiles, spawn, options.layers, world_x, world_y));
+        }
+    }
+    bitmap
+}
@@ -0,0 +1,210 @@
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use log::error;
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use crate::SaveError;
+
+/// Replaces the file at `path` with whatever `f` writes. The new contents go to a temporary file
+/// that is renamed over the old one, which is first kept as `<file>.bak` along with up to
+/// `backups - 1` older ones named `<file>.bak.2`, `<file>.bak.3` and so on.
+pub fn write_file(path: &Path, backups: usize, f: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>) -> Result<(), SaveError> {
+    let mut batch = Batch::new(backups);
+    batch.write(path, f)?;
+    batch.commit()
+}
+
+/// Files that are replaced together, like `write_file` but none of them is touched
+/// until every one has been written
+pub struct Batch {
+    backups: usize,
+    /// Temporary files and the files they replace
+    files: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Batch {
+    pub fn new(backups: usize) -> Self {
+        Batch { backups, files: Vec::new() }
+    }
+
+    /// Writes the new contents of `path` to its temporary file
+    pub fn write(&mut self, path: &Path, f: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>) -> Result<(), SaveError> {
+        let tmp = with_suffix(path, ".tmp");
+        if let Err(e) = write_tmp(&tmp, f) {
+            let _ = fs::remove_file(&tmp);
+            return Err(SaveError::Io(tmp, e));
+        }
+        self.files.push((tmp, path.to_path_buf()));
+        Ok(())
+    }
+
+    /// Moves every file into place. Should that fail part way, the files already replaced
+    /// get their old contents back so the set stays matched.
+    pub fn commit(mut self) -> Result<(), SaveError> {
+        let files = std::mem::take(&mut self.files);
+        let mut replaced = Vec::new();
+        for (i, (tmp, path)) in files.iter().enumerate() {
+            let result = self.keep(path).and_then(|previous| match fs::rename(tmp, path) {
+                Ok(()) => Ok(previous),
+                Err(e) => {
+                    // The file still holds its old contents
+                    previous.discard();
+                    Err(SaveError::Io(path.to_path_buf(), e))
+                }
+            });
+            match result {
+                Ok(previous) => replaced.push((path, previous)),
+                Err(e) => {
+                    for (path, previous) in replaced {
+                        previous.restore(path);
+                    }
+                    for (tmp, _) in &files[i..] {
+                        let _ = fs::remove_file(tmp);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        for (_, previous) in replaced {
+            previous.discard();
+        }
+        Ok(())
+    }
+
+    /// Saves what `path` holds before it is replaced, as a backup or otherwise a private copy
+    fn keep(&self, path: &Path) -> Result<Previous, SaveError> {
+        if !path.exists() {
+            Ok(Previous::Missing)
+        } else if self.backups > 0 {
+            rotate_backups(path, self.backups)?;
+            Ok(Previous::Backup)
+        } else {
+            let copy = with_suffix(path, ".old");
+            fs::copy(path, &copy).map_err(|e| SaveError::Io(copy.clone(), e))?;
+            Ok(Previous::Copy(copy))
+        }
+    }
+}
+
+impl Drop for Batch {
+    /// Cleans up after a batch that was never committed
+    fn drop(&mut self) {
+        for (tmp, _) in &self.files {
+            let _ = fs::remove_file(tmp);
+        }
+    }
+}
+
+/// Where the contents a file had before a batch can be found
+enum Previous {
+    /// The file did not exist
+    Missing,
+    /// In its newest backup
+    Backup,
+    /// In a copy made because no backups are kept
+    Copy(PathBuf),
+}
+
+impl Previous {
+    /// Puts the old contents back into `path`, files that did not exist are removed
+    fn restore(self, path: &Path) {
+        let result = match &self {
+            Previous::Missing => fs::remove_file(path),
+            Previous::Backup => fs::copy(backup_path(path, 1), path).map(|_| ()),
+            Previous::Copy(copy) => fs::rename(copy, path),
+        };
+        if let Err(e) = result {
+            error!("failed to restore {}: {}", path.display(), e);
+        }
+    }
+
+    fn discard(self) {
+        if let Previous::Copy(copy) = self {
+            let _ = fs::remove_file(copy);
+        }
+    }
+}
+
+fn write_tmp(tmp: &Path, f: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(tmp)?);
+    f(&mut file)?;
+    file.into_inner().map_err(|e| e.into_error())?.sync_all()
+}
+
+/// Shifts every backup one place back, dropping the oldest, and copies `path` to the newest.
+/// Copying rather than moving means `path` never goes missing, even for a moment.
+fn rotate_backups(path: &Path, backups: usize) -> Result<(), SaveError> {
+    let oldest = backup_path(path, backups);
+    if oldest.exists() {
+        fs::remove_file(&oldest).map_err(|e| SaveError::Io(oldest, e))?;
+    }
+    for n in (1..backups).rev() {
+        let from = backup_path(path, n);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, n + 1)).map_err(|e| SaveError::Io(from, e))?;
+        }
+    }
+    let newest = backup_path(path, 1);
+    fs::copy(path, &newest).map_err(|e| SaveError::Io(newest, e))?;
+    Ok(())
+}
+
+/// `<file>.bak` for the newest backup, `<file>.bak.<n>` for older ones
+fn backup_path(path: &Path, n: usize) -> PathBuf {
+    if n == 1 {
+        with_suffix(path, ".bak")
+    } else {
+        with_suffix(path, &format!(".bak.{}", n))
+    }
+}
+
+pub fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.file_name().unwrap_or_default());
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Writes `value` as JSON indented with tabs, the way the game's own files are
+pub fn write_json(file: &mut impl Write, value: &impl Serialize) -> io::Result<()> {
+    let mut serializer = serde_json::Serializer::with_formatter(&mut *file, PrettyFormatter::with_indent(b"\t"));
+    value.serialize(&mut serializer)?;
+    writeln!(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Replaces `a` and `b` in a batch whose rename of `b` fails
+    fn failed_batch(backups: usize) {
+        let dir = std::env::temp_dir().join(format!("trumptor-batch-{}-{}", std::process::id(), backups));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let (a, b) = (dir.join("a"), dir.join("b"));
+        fs::write(&a, "old").unwrap();
+
+        let mut batch = Batch::new(backups);
+        batch.write(&a, |file| write!(file, "new")).unwrap();
+        batch.write(&b, |file| write!(file, "new")).unwrap();
+        fs::remove_file(with_suffix(&b, ".tmp")).unwrap();
+        assert!(batch.commit().is_err());
+
+        assert_eq!(fs::read_to_string(&a).unwrap(), "old");
+        assert!(!with_suffix(&a, ".tmp").exists());
+        assert!(!with_suffix(&b, ".tmp").exists());
+        assert!(!with_suffix(&a, ".old").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_batch_without_backups_keeps_old_files() {
+        failed_batch(0);
+    }
+
+    #[test]
+    fn failed_batch_with_backups_keeps_old_files() {
+        failed_batch(1);
+    }
+}
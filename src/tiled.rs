@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use crate::history::Snapshot;
+use crate::layer::Layer;
+use crate::meta::LevelMeta;
+use crate::tiles::TileSet;
+use crate::{save, ChunkType, LoadError, SaveError, CHUNK_SIZE};
+
+const TILED_VERSION: &str = "1.10";
+/// Tiled keeps flip and rotation flags in the top bits of a tile's global id
+const GID_FLAGS: u32 = 0xf000_0000;
+/// Tile property naming the trumptor tile a Tiled tile stands for
+const TILE_PROPERTY: &str = "tile";
+const SPAWN: &str = "spawn";
+
+/// Writes the level as a Tiled map at `path`, with its tileset next to it as `<name>.tsx` and `<name>_tileset.png`
+pub fn export(level: &Snapshot, tiles: &TileSet, path: &Path) -> Result<(), SaveError> {
+    let tsx = path.with_extension("tsx");
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let atlas = path.with_file_name(format!("{}_tileset.png", stem));
+    let file_name = |path: &Path| path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+
+    // Air is left out, Tiled's empty cell stands for it
+    let used: Vec<_> = tiles.blocks().collect();
+    let gids: HashMap<ChunkType, usize> = used.iter().enumerate().map(|(i, t)| (ChunkType(t.def.id), i + 1)).collect();
+
+    let size = CHUNK_SIZE as usize;
+    let columns = tiles.atlas_columns();
+    let bitmap = tiles.atlas();
+    bitmap.save(&atlas).map_err(|e| SaveError::Image(atlas.clone(), e))?;
+
+    save::write_file(&tsx, 0, |file| {
+        writeln!(file, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(file, r#"<tileset version="{}" name="{}" tilewidth="{size}" tileheight="{size}" tilecount="{}" columns="{}">"#,
+            TILED_VERSION, escape(&stem), used.len(), columns)?;
+        writeln!(file, r#" <image source="{}" width="{}" height="{}"/>"#, escape(&file_name(&atlas)), bitmap.width, bitmap.height)?;
+        for (i, tile) in used.iter().enumerate() {
+            writeln!(file, r#" <tile id="{}">"#, i)?;
+            writeln!(file, "  <properties>")?;
+            write_property(file, "   ", TILE_PROPERTY, "string", &tile.def.name)?;
+            write_property(file, "   ", "solid", "bool", &tile.def.solid.to_string())?;
+            write_property(file, "   ", "hazard", "bool", &tile.def.hazard.to_string())?;
+            write_property(file, "   ", "checkpoint", "bool", &tile.def.checkpoint.to_string())?;
+            writeln!(file, "  </properties>")?;
+            writeln!(file, " </tile>")?;
+        }
+        writeln!(file, "</tileset>")
+    })?;
+
+    let (width, height) = (level.chunks[0].width(), level.chunks[0].height());
+    save::write_file(path, 0, |file| {
+        writeln!(file, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(file, r#"<map version="{}" orientation="orthogonal" renderorder="right-down" width="{}" height="{}" tilewidth="{size}" tileheight="{size}" infinite="0" nextlayerid="4" nextobjectid="2">"#,
+            TILED_VERSION, width, height)?;
+        let meta = &level.meta;
+        writeln!(file, " <properties>")?;
+        write_property(file, "  ", "name", "string", &meta.name)?;
+        write_property(file, "  ", "author", "string", &meta.author)?;
+        write_property(file, "  ", "music", "string", &meta.music)?;
+        write_property(file, "  ", "next_level", "string", &meta.next_level)?;
+        write_property(file, "  ", "depth", "int", &meta.depth.to_string())?;
+        write_property(file, "  ", "time_limit", "int", &meta.time_limit.to_string())?;
+        writeln!(file, " </properties>")?;
+        writeln!(file, r#" <tileset firstgid="1" source="{}"/>"#, escape(&file_name(&tsx)))?;
+        // Tiled draws layers in file order, so the background goes first
+        write_layer(file, 1, "Background", &level.chunks[1], &gids)?;
+        write_layer(file, 2, "Foreground", &level.chunks[0], &gids)?;
+        let (y, x) = level.spawn_chunk;
+        writeln!(file, r#" <objectgroup id="3" name="Objects">"#)?;
+        writeln!(file, r#"  <object id="1" name="Spawn" type="{}" x="{}" y="{}" width="{size}" height="{size}"/>"#, SPAWN, x * size, y * size)?;
+        writeln!(file, " </objectgroup>")?;
+        writeln!(file, "</map>")
+    })
+}
+
+fn write_layer(file: &mut impl Write, id: usize, name: &str, layer: &Layer, gids: &HashMap<ChunkType, usize>) -> io::Result<()> {
+    writeln!(file, r#" <layer id="{}" name="{}" width="{}" height="{}">"#, id, name, layer.width(), layer.height())?;
+    writeln!(file, r#"  <data encoding="csv">"#)?;
+    let rows: Vec<String> = layer.rows()
+        .map(|row| row.iter().map(|chunk| gids.get(chunk).copied().unwrap_or(0).to_string()).collect::<Vec<_>>().join(","))
+        .collect();
+    writeln!(file, "{}", rows.join(",\n"))?;
+    writeln!(file, "  </data>")?;
+    writeln!(file, " </layer>")
+}
+
+fn write_property(file: &mut impl Write, indent: &str, name: &str, kind: &str, value: &str) -> io::Result<()> {
+    writeln!(file, r#"{}<property name="{}" type="{}" value="{}"/>"#, indent, name, kind, escape(value))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Reads a Tiled map. Its tiles are matched to ours through their `tile` property, or failing that
+/// their type; the layers named Foreground and Background (or else the first two) become ours and
+/// an object named or typed Spawn places the spawn.
+pub fn import(path: &Path, tiles: &TileSet) -> Result<Snapshot, LoadError> {
+    let error = |message: String| LoadError::Tiled { path: path.to_path_buf(), message };
+    let text = fs::read_to_string(path).map_err(|e| LoadError::Io(path.to_path_buf(), e))?;
+    let doc = roxmltree::Document::parse(&text).map_err(|e| LoadError::Xml(path.to_path_buf(), e))?;
+    let map = doc.root_element();
+    if !map.has_tag_name("map") {
+        return Err(error("not a Tiled map".to_string()));
+    }
+    if map.attribute("infinite") == Some("1") {
+        return Err(error("infinite maps are not supported".to_string()));
+    }
+    let number = |node: roxmltree::Node, name: &str| -> Result<usize, LoadError> {
+        node.attribute(name)
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| error(format!("<{}> needs a {} number", node.tag_name().name(), name)))
+    };
+    let (width, height) = (number(map, "width")?, number(map, "height")?);
+    if width == 0 || height == 0 {
+        return Err(LoadError::InvalidSize { path: path.to_path_buf(), width, height });
+    }
+
+    let mut gids = HashMap::new();
+    for tileset in map.children().filter(|n| n.has_tag_name("tileset")) {
+        let first = number(tileset, "firstgid")? as u32;
+        match tileset.attribute("source") {
+            Some(source) => {
+                let tsx = path.parent().unwrap_or(Path::new("")).join(source);
+                let text = fs::read_to_string(&tsx).map_err(|e| LoadError::Io(tsx.clone(), e))?;
+                let doc = roxmltree::Document::parse(&text).map_err(|e| LoadError::Xml(tsx.clone(), e))?;
+                read_tileset(&tsx, doc.root_element(), first, tiles, &mut gids)?;
+            }
+            None => read_tileset(path, tileset, first, tiles, &mut gids)?,
+        }
+    }
+
+    let layers: Vec<_> = map.children().filter(|n| n.has_tag_name("layer")).collect();
+    let named = |name: &str| layers.iter().find(|l| l.attribute("name").is_some_and(|n| n.eq_ignore_ascii_case(name)));
+    let (fg, bg) = match (named("foreground"), named("background")) {
+        (Some(fg), bg) => (Some(fg), bg),
+        (None, Some(bg)) => (None, Some(bg)),
+        (None, None) => (layers.first(), layers.get(1)),
+    };
+    let read = |layer: Option<&roxmltree::Node>| match layer {
+        Some(layer) => read_layer(path, *layer, width, height, &gids),
+        None => Ok(Layer::new(width, height)),
+    };
+    let chunks = [read(fg)?, read(bg)?];
+
+    let spawn = map.children()
+        .filter(|n| n.has_tag_name("objectgroup"))
+        .flat_map(|group| group.children().filter(|n| n.has_tag_name("object")))
+        .find(|object| ["name", "type", "class"].iter().any(|a| object.attribute(*a).is_some_and(|v| v.eq_ignore_ascii_case(SPAWN))));
+    let spawn_chunk = match spawn {
+        Some(object) => {
+            let coordinate = |name: &str| object.attribute(name).and_then(|v| v.parse::<f32>().ok()).unwrap_or(0.0).max(0.0) as usize;
+            // Tile objects hang above their position, rectangles below it
+            let mut y = coordinate("y");
+            if object.attribute("gid").is_some() {
+                y = y.saturating_sub(1);
+            }
+            let size = CHUNK_SIZE as usize;
+            ((y / size).min(height - 1), (coordinate("x") / size).min(width - 1))
+        }
+        None => (0, 0),
+    };
+
+    let mut meta = LevelMeta::default();
+    let properties = map.children().filter(|n| n.has_tag_name("properties")).flat_map(|p| p.children().filter(|n| n.has_tag_name("property")));
+    for property in properties {
+        let value = property.attribute("value").unwrap_or_default().to_string();
+        match property.attribute("name").unwrap_or_default() {
+            "name" => meta.name = value,
+            "author" => meta.author = value,
+            "music" => meta.music = value,
+            "next_level" => meta.next_level = value,
+            "depth" => meta.depth = value.parse().unwrap_or(meta.depth),
+            "time_limit" => meta.time_limit = value.parse().unwrap_or(meta.time_limit),
+            _ => {}
+        }
+    }
+
+    Ok(Snapshot { chunks, spawn_chunk, chunk_mode: 0, meta })
+}
+
+/// Adds the global ids of a tileset's tiles that stand for one of ours to `gids`
+fn read_tileset(path: &Path, tileset: roxmltree::Node, first: u32, tiles: &TileSet, gids: &mut HashMap<u32, ChunkType>) -> Result<(), LoadError> {
+    for tile in tileset.children().filter(|n| n.has_tag_name("tile")) {
+        let id: u32 = tile.attribute("id").and_then(|id| id.parse().ok())
+            .ok_or_else(|| LoadError::Tiled { path: path.to_path_buf(), message: "<tile> needs an id".to_string() })?;
+        let property = tile.descendants()
+            .find(|n| n.has_tag_name("property") && n.attribute("name") == Some(TILE_PROPERTY))
+            .and_then(|n| n.attribute("value"));
+        let name = property.or_else(|| tile.attribute("type")).or_else(|| tile.attribute("class"));
+        if let Some(name) = name {
+            let chunk = tiles.find(name).ok_or_else(|| LoadError::UnknownTileName { path: path.to_path_buf(), name: name.to_string() })?;
+            gids.insert(first + id, chunk);
+        }
+    }
+    Ok(())
+}
+
+fn read_layer(path: &Path, layer: roxmltree::Node, width: usize, height: usize, gids: &HashMap<u32, ChunkType>) -> Result<Layer, LoadError> {
+    let name = layer.attribute("name").unwrap_or_default();
+    let error = |message: String| LoadError::Tiled { path: path.to_path_buf(), message: format!("layer {:?}: {}", name, message) };
+    let data = layer.children().find(|n| n.has_tag_name("data")).ok_or_else(|| error("has no data".to_string()))?;
+    if data.attribute("encoding") != Some("csv") || data.attribute("compression").is_some() {
+        return Err(error("only CSV tile layer data is supported".to_string()));
+    }
+    let cells: Vec<&str> = data.text().unwrap_or_default().split(',').map(str::trim).collect();
+    if cells.len() != width * height {
+        return Err(error(format!("has {} tiles, expected {}", cells.len(), width * height)));
+    }
+    let mut result = Layer::new(width, height);
+    for (i, cell) in cells.iter().enumerate() {
+        let gid = cell.parse::<u32>().map_err(|_| error(format!("{:?} is not a tile", cell)))? & !GID_FLAGS;
+        if gid == 0 {
+            continue;
+        }
+        result[i / width][i % width] = *gids.get(&gid).ok_or_else(|| error(format!("tile {} has no `tile` property naming one of ours", gid)))?;
+    }
+    Ok(result)
+}
@@ -0,0 +1,367 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use log::error;
+use serde::{Deserialize, Serialize};
+use winit::event::VirtualKeyCode;
+use crate::images::{self, Bitmap, Image, ImageError};
+use crate::{save, ChunkType, SaveError};
+
+/// The definitions used when no tile file is given
+const BUILTIN_TILES: &str = include_str!("../assets/tiles.json");
+/// Where the game's tiles are read from when the editor runs next to its assets,
+/// so changes to them show up without a rebuild
+const ASSETS_TILES: &str = "assets/tiles.json";
+/// Where the art of blocks made in the editor goes, relative to the definition file
+const CUSTOM_DIR: &str = "custom";
+/// Tiles to a row in the atlas written for other editors
+const ATLAS_COLUMNS: usize = 8;
+/// Hotkeys handed out to new blocks, in order
+const FREE_HOTKEYS: [&str; 13] = ["9", "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12"];
+
+/// A tile as written in the definition file
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TileDef {
+    pub id: u16,
+    pub name: String,
+    /// PNG with the tile's art, relative to the definition file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Cell of the definition file's atlas holding the tile's art, used when there is no `image`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atlas_index: Option<usize>,
+    /// `0`-`9` or `F1`-`F12`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hotkey: Option<String>,
+    /// Blocks the player, and hides the background tile behind it
+    #[serde(default)]
+    pub solid: bool,
+    /// Hurts the player on contact
+    #[serde(default)]
+    pub hazard: bool,
+    /// Saves the player's progress when touched
+    #[serde(default)]
+    pub checkpoint: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TileFile {
+    /// PNG sheet of 32x32 tiles, relative to the definition file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    atlas: Option<String>,
+    tiles: Vec<TileDef>,
+}
+
+pub struct Tile {
+    pub def: TileDef,
+    pub hotkey: Option<VirtualKeyCode>,
+    pub image: Image,
+    /// Brought in by a project, it is left out when the definition file is rewritten
+    session: bool,
+}
+
+#[derive(Debug)]
+pub enum TileError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error),
+    MissingAir(PathBuf),
+    DuplicateId(PathBuf, u16),
+    UnknownHotkey(PathBuf, String),
+    Image(PathBuf, ImageError),
+    NoFreeId(PathBuf),
+    Save(SaveError),
+}
+
+impl fmt::Display for TileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TileError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            TileError::Json(path, e) => write!(f, "{}: {}", path.display(), e),
+            TileError::MissingAir(path) => write!(f, "{}: tile id 0 must be defined as the empty tile", path.display()),
+            TileError::DuplicateId(path, id) => write!(f, "{}: tile id {} is defined more than once", path.display(), id),
+            TileError::UnknownHotkey(path, key) => write!(f, "{}: unknown hotkey {:?}, use 0-9 or F1-F12", path.display(), key),
+            TileError::Image(path, e) => write!(f, "{}: {}", path.display(), e),
+            TileError::NoFreeId(path) => write!(f, "{}: every tile id is in use", path.display()),
+            TileError::Save(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TileError {}
+
+/// Every tile the editor knows about, sorted by id
+pub struct TileSet {
+    tiles: Vec<Tile>,
+    placeholder: Image,
+    /// The definition file, `None` for the built-in tiles
+    source: Option<PathBuf>,
+    /// Directory the art paths are relative to, `None` when the art ships inside the editor
+    art_dir: Option<PathBuf>,
+    atlas: Option<String>,
+}
+
+impl TileSet {
+    /// The game's own tiles, read from `assets/tiles.json` when it is there
+    /// and otherwise with the art shipped inside the editor
+    pub fn builtin() -> Self {
+        let path = Path::new(ASSETS_TILES);
+        if let Ok(text) = fs::read_to_string(path) {
+            match Self::parse(path, &text, path.parent()) {
+                Ok(mut tiles) => {
+                    tiles.source = None;
+                    return tiles;
+                }
+                Err(e) => error!("{}, using the tiles shipped with the editor", e),
+            }
+        }
+        Self::parse(Path::new("<builtin>"), BUILTIN_TILES, None).expect("built-in tile definitions are valid")
+    }
+
+    /// Reads a definition file, loading its art from the PNGs it references
+    pub fn load(path: &Path) -> Result<Self, TileError> {
+        let text = fs::read_to_string(path).map_err(|e| TileError::Io(path.to_path_buf(), e))?;
+        Self::parse(path, &text, Some(path.parent().unwrap_or(Path::new(""))))
+    }
+
+    /// Without an `art_dir` only the built-in art is used
+    fn parse(path: &Path, text: &str, art_dir: Option<&Path>) -> Result<Self, TileError> {
+        let file: TileFile = serde_json::from_str(text).map_err(|e| TileError::Json(path.to_path_buf(), e))?;
+        let atlas = match (art_dir, &file.atlas) {
+            (Some(dir), Some(atlas)) => Bitmap::load(&dir.join(atlas))
+                .map_err(|e| error!("{}: {}, using built-in art", dir.join(atlas).display(), e))
+                .ok(),
+            _ => None,
+        };
+
+        let mut tiles = Vec::with_capacity(file.tiles.len());
+        for def in file.tiles {
+            let hotkey = match &def.hotkey {
+                Some(key) => Some(parse_hotkey(key).ok_or_else(|| TileError::UnknownHotkey(path.to_path_buf(), key.clone()))?),
+                None => None,
+            };
+            let image = if def.id == ChunkType::AIR.0 {
+                images::BLANK
+            } else {
+                load_art(&def, art_dir, atlas.as_ref())
+                    .or_else(|| images::builtin(&def.name))
+                    .unwrap_or_else(images::placeholder)
+            };
+            tiles.push(Tile { def, hotkey, image, session: false });
+        }
+
+        tiles.sort_by_key(|t| t.def.id);
+        if let Some(pair) = tiles.windows(2).find(|pair| pair[0].def.id == pair[1].def.id) {
+            return Err(TileError::DuplicateId(path.to_path_buf(), pair[0].def.id));
+        }
+        if tiles.first().map(|t| t.def.id) != Some(ChunkType::AIR.0) {
+            return Err(TileError::MissingAir(path.to_path_buf()));
+        }
+
+        Ok(TileSet {
+            tiles,
+            placeholder: images::placeholder(),
+            source: art_dir.map(|_| path.to_path_buf()),
+            art_dir: art_dir.map(Path::to_path_buf),
+            atlas: file.atlas,
+        })
+    }
+
+    pub fn get(&self, chunk: ChunkType) -> Option<&Tile> {
+        self.tiles
+            .binary_search_by_key(&chunk.0, |t| t.def.id)
+            .ok()
+            .map(|i| &self.tiles[i])
+    }
+
+    pub fn source(&self) -> Option<&Path> {
+        self.source.as_deref()
+    }
+
+    pub fn name_taken(&self, name: &str) -> bool {
+        self.tiles.iter().any(|t| t.def.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds a block made in the editor, writing its art next to the definition file at `path`
+    /// and rewriting that file to include it. Built-in tiles are written out along with it.
+    pub fn add(&mut self, mut def: TileDef, image: Image, path: &Path) -> Result<ChunkType, TileError> {
+        def.id = match self.tiles.last() {
+            Some(last) => last.def.id.checked_add(1).ok_or_else(|| TileError::NoFreeId(path.to_path_buf()))?,
+            None => 1,
+        };
+        def.hotkey = FREE_HOTKEYS
+            .iter()
+            .find(|key| !self.tiles.iter().any(|t| t.def.hotkey.as_deref() == Some(**key)))
+            .map(|key| key.to_string());
+
+        let file_name: String = def.name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        // Names differing only in case or punctuation map to the same file name, the id keeps them apart
+        let art = format!("{}/{}_{}.png", CUSTOM_DIR, def.id, file_name);
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let art_path = dir.join(&art);
+        fs::create_dir_all(dir.join(CUSTOM_DIR)).map_err(|e| TileError::Io(art_path.clone(), e))?;
+        Bitmap::from(&image).save(&art_path).map_err(|e| TileError::Image(art_path.clone(), e))?;
+        def.image = Some(art);
+
+        // Art paths have to be relative to the new file, built-in art has no files to point at
+        let moved = |art: &Option<String>| match &self.art_dir {
+            None => None,
+            Some(art_dir) if art_dir == dir => art.clone(),
+            Some(art_dir) => art.as_ref().map(|art| relative_to(&art_dir.join(art), dir)),
+        };
+        let kept = || self.tiles.iter().filter(|t| !t.session);
+        let mut defs: Vec<TileDef> = kept().map(|t| t.def.clone()).collect();
+        defs.iter_mut().for_each(|def| def.image = moved(&def.image));
+        let atlas = moved(&self.atlas);
+        defs.push(def.clone());
+        let file = TileFile { atlas: atlas.clone(), tiles: defs };
+        save::write_file(path, 0, |out| {
+            serde_json::to_writer_pretty(&mut *out, &file)?;
+            writeln!(out)
+        }).map_err(TileError::Save)?;
+
+        for (tile, def) in self.tiles.iter_mut().filter(|t| !t.session).zip(&file.tiles) {
+            tile.def.image = def.image.clone();
+        }
+        let hotkey = def.hotkey.as_deref().and_then(parse_hotkey);
+        let id = ChunkType(def.id);
+        self.tiles.push(Tile { def, hotkey, image, session: false });
+        self.source = Some(path.to_path_buf());
+        self.art_dir = Some(dir.to_path_buf());
+        self.atlas = atlas;
+
+        Ok(id)
+    }
+
+    /// The tile with this name, ignoring case
+    pub fn find(&self, name: &str) -> Option<ChunkType> {
+        self.tiles.iter().find(|t| t.def.name.eq_ignore_ascii_case(name)).map(|t| ChunkType(t.def.id))
+    }
+
+    /// Adds a tile brought in by the project at `path` for this session only. Its id and hotkey
+    /// are kept unless another tile already has them.
+    pub fn insert(&mut self, mut def: TileDef, image: Image, path: &Path) -> Result<ChunkType, TileError> {
+        if self.contains(ChunkType(def.id)) {
+            def.id = match self.tiles.last() {
+                Some(last) => last.def.id.checked_add(1).ok_or_else(|| TileError::NoFreeId(path.to_path_buf()))?,
+                None => 1,
+            };
+        }
+        if self.tiles.iter().any(|t| t.def.hotkey.is_some() && t.def.hotkey == def.hotkey) {
+            def.hotkey = None;
+        }
+        def.image = None;
+        def.atlas_index = None;
+        let hotkey = def.hotkey.as_deref().and_then(parse_hotkey);
+        let id = ChunkType(def.id);
+        let index = self.tiles.partition_point(|t| t.def.id < def.id);
+        self.tiles.insert(index, Tile { def, hotkey, image, session: true });
+        Ok(id)
+    }
+
+    /// The art of a tile, unknown tiles get a placeholder
+    pub fn image(&self, chunk: ChunkType) -> &Image {
+        self.get(chunk).map_or(&self.placeholder, |t| &t.image)
+    }
+
+    pub fn contains(&self, chunk: ChunkType) -> bool {
+        self.get(chunk).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Tile> {
+        self.tiles.iter()
+    }
+
+    /// Every tile but air
+    pub fn blocks(&self) -> impl Iterator<Item = &Tile> {
+        self.tiles.iter().filter(|t| t.def.id != ChunkType::AIR.0)
+    }
+
+    /// Tiles to a row in `atlas`
+    pub fn atlas_columns(&self) -> usize {
+        self.blocks().count().clamp(1, ATLAS_COLUMNS)
+    }
+
+    /// The art of every block in one picture, in the order of `blocks`
+    pub fn atlas(&self) -> Bitmap {
+        let count = self.blocks().count();
+        let columns = self.atlas_columns();
+        let mut bitmap = Bitmap::new(columns * 32, count.div_ceil(columns).max(1) * 32);
+        for (i, tile) in self.blocks().enumerate() {
+            let (cell_x, cell_y) = (i % columns * 32, i / columns * 32);
+            for (y, row) in tile.image.iter().enumerate() {
+                for (x, pixel) in row.iter().enumerate() {
+                    bitmap.set(cell_x + x, cell_y + y, *pixel);
+                }
+            }
+        }
+        bitmap
+    }
+
+    pub fn is_solid(&self, chunk: ChunkType) -> bool {
+        self.get(chunk).is_some_and(|t| t.def.solid)
+    }
+
+    /// The first placeable tile, used as the initial brush
+    pub fn first_block(&self) -> ChunkType {
+        self.tiles.get(1).map_or(ChunkType::AIR, |t| ChunkType(t.def.id))
+    }
+
+    /// The tile `step` places after (or with a negative step before) `chunk`, wrapping around
+    pub fn cycle(&self, chunk: ChunkType, step: isize) -> ChunkType {
+        let current = self.tiles.iter().position(|t| t.def.id == chunk.0).unwrap_or(0) as isize;
+        let next = (current + step).rem_euclid(self.tiles.len() as isize) as usize;
+        ChunkType(self.tiles[next].def.id)
+    }
+}
+
+/// Reads a tile's art from its own PNG or the atlas, failures are logged so the built-in art can stand in
+fn load_art(def: &TileDef, art_dir: Option<&Path>, atlas: Option<&Bitmap>) -> Option<Image> {
+    let art_dir = art_dir?;
+    if let Some(image) = &def.image {
+        let path = art_dir.join(image);
+        return Bitmap::load(&path)
+            .and_then(|bitmap| bitmap.to_image())
+            .map_err(|e| error!("{}: {}, using built-in art", path.display(), e))
+            .ok();
+    }
+    match (atlas, def.atlas_index) {
+        (Some(atlas), Some(index)) => atlas
+            .tile(index)
+            .map_err(|e| error!("atlas for {}: {}, using built-in art", def.name, e))
+            .ok()
+            .flatten(),
+        _ => None,
+    }
+}
+
+/// `path` as seen from `base`, falling back to an absolute path when they share no root
+fn relative_to(path: &Path, base: &Path) -> String {
+    let absolute = |path: &Path| std::path::absolute(if path.as_os_str().is_empty() { Path::new(".") } else { path });
+    let (path, base) = match (absolute(path), absolute(base)) {
+        (Ok(path), Ok(base)) => (path, base),
+        _ => return path.to_string_lossy().replace('\\', "/"),
+    };
+    let common = path.components().zip(base.components()).take_while(|(a, b)| a == b).count();
+    if common == 0 {
+        return path.to_string_lossy().replace('\\', "/");
+    }
+    let mut relative = PathBuf::new();
+    base.components().skip(common).for_each(|_| relative.push(".."));
+    relative.extend(path.components().skip(common));
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+fn parse_hotkey(key: &str) -> Option<VirtualKeyCode> {
+    use VirtualKeyCode::*;
+    Some(match key {
+        "0" => Key0, "1" => Key1, "2" => Key2, "3" => Key3, "4" => Key4,
+        "5" => Key5, "6" => Key6, "7" => Key7, "8" => Key8, "9" => Key9,
+        "F1" => F1, "F2" => F2, "F3"  => F3,  "F4"  => F4,  "F5"  => F5,  "F6"  => F6,
+        "F7" => F7, "F8" => F8, "F9"  => F9,  "F10" => F10, "F11" => F11, "F12" => F12,
+        _ => return None,
+    })
+}
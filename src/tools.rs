@@ -0,0 +1,77 @@
+use crate::layer::Layer;
+
+/// What holding the left mouse button does
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tool {
+    Pencil,
+    Rect,
+    RectErase,
+    Fill,
+    Line,
+}
+
+/// Orders two `(y, x)` corners into the `(y0, x0, y1, x1)` bounds of the rectangle they span
+pub fn rect(a: (usize, usize), b: (usize, usize)) -> (usize, usize, usize, usize) {
+    (a.0.min(b.0), a.1.min(b.1), a.0.max(b.0), a.1.max(b.1))
+}
+
+/// Every `(y, x)` tile on the Bresenham line from `a` to `b`, both ends included
+pub fn line(a: (usize, usize), b: (usize, usize)) -> Vec<(usize, usize)> {
+    let (mut y, mut x) = (a.0 as isize, a.1 as isize);
+    let (y1, x1) = (b.0 as isize, b.1 as isize);
+    let dx = (x1 - x).abs();
+    let dy = -(y1 - y).abs();
+    let sx = if x < x1 { 1 } else { -1 };
+    let sy = if y < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    let mut tiles = Vec::new();
+    loop {
+        tiles.push((y as usize, x as usize));
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = 2 * err;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+    tiles
+}
+
+/// Collects the 4-connected region of tiles matching the one at `start`, as `(y, x)` pairs.
+/// Tiles for which `blocked(x, y)` holds are neither filled nor crossed.
+pub fn flood_fill(layer: &Layer, start: (usize, usize), blocked: impl Fn(usize, usize) -> bool) -> Vec<(usize, usize)> {
+    let (y, x) = start;
+    let target = match layer.get(x, y) {
+        Some(chunk) if !blocked(x, y) => chunk,
+        _ => return Vec::new(),
+    };
+
+    let mut seen = vec![false; layer.width() * layer.height()];
+    let mut stack = vec![(y, x)];
+    let mut region = Vec::new();
+    seen[y * layer.width() + x] = true;
+    while let Some((y, x)) = stack.pop() {
+        region.push((y, x));
+        let neighbours = [
+            (y, x.wrapping_sub(1)),
+            (y, x + 1),
+            (y.wrapping_sub(1), x),
+            (y + 1, x),
+        ];
+        for (ny, nx) in neighbours {
+            if layer.get(nx, ny) != Some(target) || seen[ny * layer.width() + nx] || blocked(nx, ny) {
+                continue;
+            }
+            seen[ny * layer.width() + nx] = true;
+            stack.push((ny, nx));
+        }
+    }
+    region
+}